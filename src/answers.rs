@@ -0,0 +1,217 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::io::InputError;
+use crate::solution::{Part, Puzzle, PuzzleId};
+
+#[derive(Debug)]
+pub enum AnswerError {
+  /// The puzzle input to check against could not be read.
+  Input(InputError),
+  Io(io::Error),
+}
+
+impl Display for AnswerError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      AnswerError::Input(e) => write!(f, "{}", e),
+      AnswerError::Io(e) => write!(f, "failed to read or write answers: {}", e),
+    }
+  }
+}
+
+impl std::error::Error for AnswerError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      AnswerError::Input(e) => Some(e),
+      AnswerError::Io(e) => Some(e),
+    }
+  }
+}
+
+impl From<InputError> for AnswerError {
+  fn from(e: InputError) -> Self {
+    AnswerError::Input(e)
+  }
+}
+
+impl From<io::Error> for AnswerError {
+  fn from(e: io::Error) -> Self {
+    AnswerError::Io(e)
+  }
+}
+
+/// Known good answers, one `YYYY-DD` file per puzzle holding part one and part two on
+/// separate lines. An empty line means the answer is not known yet.
+pub struct AnswerStore {
+  dir: PathBuf,
+}
+
+impl AnswerStore {
+  pub fn new(dir: &Path) -> AnswerStore {
+    AnswerStore {
+      dir: dir.to_path_buf(),
+    }
+  }
+
+  fn path(&self, id: PuzzleId) -> PathBuf {
+    self.dir.join(id.to_string())
+  }
+
+  fn read(&self, id: PuzzleId) -> Result<[Option<String>; 2], AnswerError> {
+    let contents = match fs::read_to_string(self.path(id)) {
+      Ok(contents) => contents,
+      Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+      Err(e) => return Err(e.into()),
+    };
+    let mut lines = contents
+      .lines()
+      .map(|line| Some(line.trim().to_string()).filter(|l| !l.is_empty()));
+    Ok([lines.next().unwrap_or(None), lines.next().unwrap_or(None)])
+  }
+
+  pub fn get(&self, id: PuzzleId, part: Part) -> Result<Option<String>, AnswerError> {
+    let [one, two] = self.read(id)?;
+    Ok(match part {
+      Part::One => one,
+      Part::Two => two,
+    })
+  }
+
+  pub fn set(&self, id: PuzzleId, part: Part, answer: &str) -> Result<(), AnswerError> {
+    let mut answers = self.read(id)?;
+    answers[part.number() as usize - 1] = Some(answer.to_string());
+    fs::create_dir_all(&self.dir)?;
+    let contents = format!(
+      "{}\n{}\n",
+      answers[0].as_deref().unwrap_or(""),
+      answers[1].as_deref().unwrap_or("")
+    );
+    fs::write(self.path(id), contents)?;
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Check {
+  Pass,
+  Fail { expected: String, actual: String },
+  Missing { actual: String },
+}
+
+impl Display for Check {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Check::Pass => write!(f, "pass"),
+      Check::Fail { expected, actual } => {
+        write!(f, "FAIL expected {} got {}", expected, actual)
+      }
+      Check::Missing { actual } => write!(f, "missing (got {})", actual),
+    }
+  }
+}
+
+/// Runs `puzzle` on its cached input and compares both parts against the store.
+pub fn verify(
+  puzzle: &dyn Puzzle,
+  config: &Config,
+  store: &AnswerStore,
+) -> Result<Vec<(Part, Check)>, AnswerError> {
+  let config = Config {
+    offline: true,
+    ..config.clone()
+  };
+  let report = puzzle.run(&puzzle.read_input(&config)?, None);
+  let id = puzzle.id();
+  let mut checks = vec![];
+  for (part, answer) in [(Part::One, report.part_one), (Part::Two, report.part_two)] {
+    let actual = answer.expect("both parts were run").value;
+    let check = match store.get(id, part)? {
+      Some(expected) if expected == actual => Check::Pass,
+      Some(expected) => Check::Fail { expected, actual },
+      None => Check::Missing { actual },
+    };
+    checks.push((part, check));
+  }
+  Ok(checks)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::y2015::day01::Day01;
+
+  fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+      "advent-of-rust-answers-{}-{}",
+      name,
+      std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+  }
+
+  #[test]
+  fn test_store() {
+    let store = AnswerStore::new(&temp_dir("store"));
+    let id = PuzzleId::new(2020, 1);
+    assert_eq!(store.get(id, Part::One).unwrap(), None);
+    store.set(id, Part::Two, "42").unwrap();
+    assert_eq!(store.get(id, Part::One).unwrap(), None);
+    assert_eq!(store.get(id, Part::Two).unwrap(), Some("42".to_string()));
+    store.set(id, Part::One, "7").unwrap();
+    assert_eq!(store.get(id, Part::One).unwrap(), Some("7".to_string()));
+    assert_eq!(store.get(id, Part::Two).unwrap(), Some("42".to_string()));
+  }
+
+  #[test]
+  fn test_verify() {
+    let inputs = temp_dir("verify-inputs");
+    fs::write(inputs.join("2015-01"), "(()))").unwrap();
+    let config = Config {
+      input_dir: inputs,
+      session: None,
+      offline: false,
+      base_url: "http://127.0.0.1:1".to_string(),
+      answers_dir: temp_dir("verify-answers"),
+      bench_history: std::env::temp_dir().join("unused.json"),
+    };
+    let store = AnswerStore::new(&config.answers_dir);
+    let id = PuzzleId::new(2015, 1);
+    store.set(id, Part::One, "-2").unwrap();
+
+    assert_eq!(
+      verify(&Day01, &config, &store).unwrap(),
+      vec![
+        (
+          Part::One,
+          Check::Fail {
+            expected: "-2".to_string(),
+            actual: "-1".to_string()
+          }
+        ),
+        (
+          Part::Two,
+          Check::Missing {
+            actual: "5".to_string()
+          }
+        ),
+      ]
+    );
+
+    store.set(id, Part::One, "-1").unwrap();
+    assert_eq!(verify(&Day01, &config, &store).unwrap()[0].1, Check::Pass);
+
+    let missing = PuzzleId::new(2015, 2);
+    assert!(matches!(
+      verify(&crate::y2015::day02::Day02, &config, &store),
+      Err(AnswerError::Input(InputError::Offline(_)))
+    ));
+    assert_eq!(store.get(missing, Part::One).unwrap(), None);
+  }
+}
@@ -0,0 +1,533 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use crate::geom::Point;
+
+/// Which cells count as the neighbours of a cell.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Neighbourhood {
+  /// Every cell that differs by at most one on each axis: 8 in 2D, 26 in 3D, 80 in 4D.
+  Moore,
+  /// The cells one step away along a single axis: 4 in 2D, 6 in 3D.
+  VonNeumann,
+  /// The first domain cell seen in each Moore direction, looking past the gaps.
+  /// Only meaningful with a domain, see [`Automaton::with_domain`].
+  LineOfSight,
+}
+
+impl Neighbourhood {
+  /// The unit steps this neighbourhood looks along.
+  pub fn offsets<const N: usize>(&self) -> Vec<Point<N>> {
+    match self {
+      Neighbourhood::VonNeumann => (0..N)
+        .flat_map(|axis| {
+          [-1, 1].iter().map(move |&d| {
+            let mut coords = [0; N];
+            coords[axis] = d;
+            Point(coords)
+          })
+        })
+        .collect(),
+      Neighbourhood::Moore | Neighbourhood::LineOfSight => (0..3usize.pow(N as u32))
+        .map(|mut i| {
+          Point(std::array::from_fn(|_| {
+            let c = (i % 3) as i64 - 1;
+            i /= 3;
+            c
+          }))
+        })
+        .filter(|p| *p != Point::origin())
+        .collect(),
+    }
+  }
+}
+
+/// Birth/survival rule: an inactive cell becomes active when its number of active neighbours
+/// is in `birth`, an active cell stays active when it is in `survival`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Rule {
+  birth: Vec<usize>,
+  survival: Vec<usize>,
+}
+
+impl Rule {
+  pub fn new(birth: &[usize], survival: &[usize]) -> Rule {
+    Rule {
+      birth: birth.to_vec(),
+      survival: survival.to_vec(),
+    }
+  }
+
+  /// Conway's game of life, `B3/S23`.
+  pub fn life() -> Rule {
+    Rule::new(&[3], &[2, 3])
+  }
+
+  pub fn next(&self, active: bool, neighbours: usize) -> bool {
+    if active {
+      self.survival.contains(&neighbours)
+    } else {
+      self.birth.contains(&neighbours)
+    }
+  }
+}
+
+/// Parses the usual `B3/S23` notation, with multi-digit counts separated by commas, as in
+/// `B3/S2,3,12`.
+impl FromStr for Rule {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Rule, String> {
+    let invalid = || format!("invalid rule {:?}", s);
+    let (birth, survival) = s.trim().split_once('/').ok_or_else(invalid)?;
+    let counts = |part: &str, prefix: char| -> Result<Vec<usize>, String> {
+      let digits = part.strip_prefix(prefix).ok_or_else(invalid)?;
+      if digits.contains(',') {
+        digits
+          .split(',')
+          .map(|n| n.parse().map_err(|_| invalid()))
+          .collect()
+      } else {
+        digits
+          .chars()
+          .map(|c| c.to_digit(10).map(|d| d as usize).ok_or_else(invalid))
+          .collect()
+      }
+    };
+    Ok(Rule {
+      birth: counts(birth, 'B')?,
+      survival: counts(survival, 'S')?,
+    })
+  }
+}
+
+/// The cells of a bounded automaton, numbered so a step can work on plain vectors.
+#[derive(Debug, Clone)]
+struct Domain<const N: usize> {
+  cells: Vec<Point<N>>,
+  index: HashMap<Point<N>, usize>,
+  /// Corners of the box around the domain, where lines of sight give up.
+  bounds: (Point<N>, Point<N>),
+  neighbourhood: Neighbourhood,
+  offsets: Vec<Point<N>>,
+  /// Neighbours of every cell by index, worked out once since the domain never changes.
+  neighbours: Vec<Vec<usize>>,
+}
+
+impl<const N: usize> Domain<N> {
+  fn new(
+    points: impl IntoIterator<Item = Point<N>>,
+    neighbourhood: Neighbourhood,
+    offsets: &[Point<N>],
+  ) -> Domain<N> {
+    let mut cells = vec![];
+    let mut index = HashMap::new();
+    for p in points {
+      index.entry(p).or_insert_with(|| {
+        cells.push(p);
+        cells.len() - 1
+      });
+    }
+    let mut domain = Domain {
+      bounds: bounds(&cells),
+      cells,
+      index,
+      neighbourhood,
+      offsets: offsets.to_vec(),
+      neighbours: vec![],
+    };
+    domain.neighbours = domain.cells.iter().map(|&p| domain.look(p)).collect();
+    domain
+  }
+
+  fn contains(&self, p: &Point<N>) -> bool {
+    self.index.contains_key(p)
+  }
+
+  fn neighbours(&self, p: Point<N>) -> Vec<Point<N>> {
+    let neighbours = match self.index.get(&p) {
+      Some(&i) => self.neighbours[i].clone(),
+      None => self.look(p),
+    };
+    neighbours.into_iter().map(|i| self.cells[i]).collect()
+  }
+
+  /// The indices of the neighbours of `p`, searched for rather than looked up.
+  fn look(&self, p: Point<N>) -> Vec<usize> {
+    if self.neighbourhood != Neighbourhood::LineOfSight {
+      return self
+        .offsets
+        .iter()
+        .filter_map(|&d| self.index.get(&(p + d)).copied())
+        .collect();
+    }
+    let (min, max) = self.bounds;
+    let inside = |q: &Point<N>| (0..N).all(|i| min.0[i] <= q.0[i] && q.0[i] <= max.0[i]);
+    self
+      .offsets
+      .iter()
+      .filter_map(|&d| {
+        let mut q = p + d;
+        while inside(&q) {
+          if let Some(&i) = self.index.get(&q) {
+            return Some(i);
+          }
+          q += d;
+        }
+        None
+      })
+      .collect()
+  }
+}
+
+/// How [`Automaton::run_until_stable`] ended.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Settled {
+  /// Nothing changed after this many generations.
+  Stable(usize),
+  /// Generation `start` came back `period` generations later, so it repeats for ever.
+  Cycle { start: usize, period: usize },
+}
+
+/// A cellular automaton over `N` dimensional integer points. Only the active cells are
+/// stored, so an unbounded automaton grows as far as its rule takes it. A domain restricts
+/// the cells that exist at all, like the seats of a waiting area.
+#[derive(Debug, Clone)]
+pub struct Automaton<const N: usize> {
+  active: HashSet<Point<N>>,
+  neighbourhood: Neighbourhood,
+  /// The neighbourhood's offsets, worked out once rather than for every cell.
+  offsets: Vec<Point<N>>,
+  rule: Rule,
+  domain: Option<Domain<N>>,
+  /// Whether each domain cell is active, by index, so a bounded step never hashes a point.
+  state: Vec<bool>,
+  /// The cells that flipped in each generation, oldest first, so the past can be replayed
+  /// without keeping a copy of every generation.
+  history: Vec<Vec<Point<N>>>,
+}
+
+impl<const N: usize> Automaton<N> {
+  pub fn new(
+    active: impl IntoIterator<Item = Point<N>>,
+    neighbourhood: Neighbourhood,
+    rule: Rule,
+  ) -> Automaton<N> {
+    Automaton {
+      active: active.into_iter().collect(),
+      neighbourhood,
+      offsets: neighbourhood.offsets(),
+      rule,
+      domain: None,
+      state: vec![],
+      history: vec![],
+    }
+  }
+
+  /// Restricts the automaton to `domain`. Active cells outside it are dropped.
+  pub fn with_domain(mut self, domain: impl IntoIterator<Item = Point<N>>) -> Automaton<N> {
+    let domain = Domain::new(domain, self.neighbourhood, &self.offsets);
+    self.active.retain(|p| domain.contains(p));
+    self.state = domain
+      .cells
+      .iter()
+      .map(|p| self.active.contains(p))
+      .collect();
+    self.domain = Some(domain);
+    self
+  }
+
+  pub fn active(&self) -> &HashSet<Point<N>> {
+    &self.active
+  }
+
+  pub fn is_active(&self, p: &Point<N>) -> bool {
+    self.active.contains(p)
+  }
+
+  pub fn count(&self) -> usize {
+    self.active.len()
+  }
+
+  /// The number of steps taken so far.
+  pub fn generation(&self) -> usize {
+    self.history.len()
+  }
+
+  /// The active cells as they were after `generation` steps, found by undoing the later ones.
+  pub fn at(&self, generation: usize) -> HashSet<Point<N>> {
+    assert!(
+      generation <= self.generation(),
+      "no generation {} yet",
+      generation
+    );
+    let mut active = self.active.clone();
+    for flipped in self.history[generation..].iter().rev() {
+      for p in flipped {
+        if !active.remove(p) {
+          active.insert(*p);
+        }
+      }
+    }
+    active
+  }
+
+  pub fn neighbours(&self, p: Point<N>) -> Vec<Point<N>> {
+    match &self.domain {
+      Some(domain) => domain.neighbours(p),
+      None => {
+        self.assert_unbounded_neighbourhood();
+        self.offsets.iter().map(|&d| p + d).collect()
+      }
+    }
+  }
+
+  fn assert_unbounded_neighbourhood(&self) {
+    assert_ne!(
+      self.neighbourhood,
+      Neighbourhood::LineOfSight,
+      "line of sight needs a domain"
+    );
+  }
+
+  pub fn active_neighbours(&self, p: Point<N>) -> usize {
+    self
+      .neighbours(p)
+      .iter()
+      .filter(|n| self.active.contains(n))
+      .count()
+  }
+
+  /// Advances one generation. Returns whether any cell changed.
+  pub fn step(&mut self) -> bool {
+    let domain = match &self.domain {
+      Some(domain) => domain,
+      None => {
+        let next = self.next_unbounded();
+        let flipped: Vec<Point<N>> = self.active.symmetric_difference(&next).copied().collect();
+        let changed = !flipped.is_empty();
+        self.history.push(flipped);
+        self.active = next;
+        return changed;
+      }
+    };
+    let state = self.next_in(domain);
+    let mut flipped = vec![];
+    for (i, (&was, &is)) in self.state.iter().zip(&state).enumerate() {
+      if was != is {
+        let p = domain.cells[i];
+        if is {
+          self.active.insert(p);
+        } else {
+          self.active.remove(&p);
+        }
+        flipped.push(p);
+      }
+    }
+    let changed = !flipped.is_empty();
+    self.history.push(flipped);
+    self.state = state;
+    changed
+  }
+
+  /// The next state of a bounded automaton, counting neighbours by cell index.
+  fn next_in(&self, domain: &Domain<N>) -> Vec<bool> {
+    domain
+      .neighbours
+      .iter()
+      .enumerate()
+      .map(|(i, neighbours)| {
+        let count = neighbours.iter().filter(|&&j| self.state[j]).count();
+        self.rule.next(self.state[i], count)
+      })
+      .collect()
+  }
+
+  /// The next generation of an unbounded automaton, where only cells next to an active one
+  /// can change.
+  fn next_unbounded(&self) -> HashSet<Point<N>> {
+    assert!(
+      !self.rule.next(false, 0),
+      "births without neighbours need a domain"
+    );
+    self.assert_unbounded_neighbourhood();
+    let mut counts: HashMap<Point<N>, usize> = HashMap::new();
+    for &p in &self.active {
+      for &d in &self.offsets {
+        *counts.entry(p + d).or_insert(0) += 1;
+      }
+    }
+    let survivors = self
+      .active
+      .iter()
+      .filter(|p| self.rule.next(true, counts.get(p).copied().unwrap_or(0)));
+    let births = counts
+      .iter()
+      .filter(|(p, &n)| !self.active.contains(p) && self.rule.next(false, n))
+      .map(|(p, _)| p);
+    survivors.chain(births).copied().collect()
+  }
+
+  pub fn run(&mut self, generations: usize) -> &mut Automaton<N> {
+    for _ in 0..generations {
+      self.step();
+    }
+    self
+  }
+
+  /// Steps until a generation repeats an earlier one: either a step changes nothing, or an
+  /// oscillator comes back round, in which case this stops after its first full period.
+  /// Generations are recognised by a hash of their active cells and confirmed by replaying
+  /// the history, so a collision cannot end the run early.
+  pub fn run_until_stable(&mut self) -> Settled {
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    seen.insert(self.fingerprint(), vec![self.generation()]);
+    loop {
+      if !self.step() {
+        return Settled::Stable(self.generation() - 1);
+      }
+      let generations = seen.entry(self.fingerprint()).or_default();
+      if let Some(start) = generations
+        .iter()
+        .copied()
+        .find(|&g| self.at(g) == self.active)
+      {
+        return Settled::Cycle {
+          start,
+          period: self.generation() - start,
+        };
+      }
+      generations.push(self.generation());
+    }
+  }
+
+  /// A hash of the active cells that does not depend on the order the set iterates them in.
+  fn fingerprint(&self) -> u64 {
+    self
+      .active
+      .iter()
+      .map(|p| p.0.iter().fold(0, |h, &c| mix(h ^ c as u64)))
+      .fold(0, u64::wrapping_add)
+  }
+}
+
+/// The splitmix64 finaliser, a cheap way to scatter coordinates over all 64 bits.
+fn mix(mut x: u64) -> u64 {
+  x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+  x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+  x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+  x ^ (x >> 31)
+}
+
+fn bounds<const N: usize>(points: &[Point<N>]) -> (Point<N>, Point<N>) {
+  let min = Point(std::array::from_fn(|i| {
+    points.iter().map(|p| p.0[i]).min().unwrap_or(0)
+  }));
+  let max = Point(std::array::from_fn(|i| {
+    points.iter().map(|p| p.0[i]).max().unwrap_or(0)
+  }));
+  (min, max)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::geom::{Point2, Point3, Point4};
+
+  #[test]
+  fn test_offsets() {
+    assert_eq!(Neighbourhood::Moore.offsets::<2>().len(), 8);
+    assert_eq!(Neighbourhood::Moore.offsets::<3>().len(), 26);
+    assert_eq!(Neighbourhood::Moore.offsets::<4>().len(), 80);
+    assert_eq!(Neighbourhood::VonNeumann.offsets::<2>().len(), 4);
+    assert_eq!(Neighbourhood::VonNeumann.offsets::<3>().len(), 6);
+    assert!(!Neighbourhood::Moore
+      .offsets::<4>()
+      .contains(&Point4::origin()));
+  }
+
+  #[test]
+  fn test_rule_from_str() {
+    assert_eq!("B3/S23".parse(), Ok(Rule::life()));
+    assert_eq!("B/S2,13".parse(), Ok(Rule::new(&[], &[2, 13])));
+    assert!("B3S23".parse::<Rule>().is_err());
+    assert!("S23/B3".parse::<Rule>().is_err());
+  }
+
+  #[test]
+  fn test_blinker() {
+    let row = vec![Point2::new(0, 1), Point2::new(1, 1), Point2::new(2, 1)];
+    let mut life = Automaton::new(row.clone(), Neighbourhood::Moore, Rule::life());
+    assert!(life.step());
+    assert!(life.is_active(&Point2::new(1, 0)));
+    assert!(life.is_active(&Point2::new(1, 2)));
+    assert_eq!(life.count(), 3);
+    life.run(3);
+    assert_eq!(life.generation(), 4);
+    assert_eq!(life.at(2), row.into_iter().collect());
+    assert_eq!(life.at(4), *life.active());
+    assert!(life.at(1).contains(&Point2::new(1, 0)));
+  }
+
+  #[test]
+  fn test_until_stable_oscillator() {
+    let row = vec![Point2::new(0, 1), Point2::new(1, 1), Point2::new(2, 1)];
+    let mut blinker = Automaton::new(row.clone(), Neighbourhood::Moore, Rule::life());
+    assert_eq!(
+      blinker.run_until_stable(),
+      Settled::Cycle {
+        start: 0,
+        period: 2
+      }
+    );
+    assert_eq!(blinker.generation(), 2);
+    assert_eq!(*blinker.active(), row.into_iter().collect());
+
+    // A glider never repeats a generation exactly, only shifted, so give it a box to settle in.
+    let domain = (0..4).flat_map(|x| (0..4).map(move |y| Point2::new(x, y)));
+    let glider = vec![
+      Point2::new(1, 0),
+      Point2::new(2, 1),
+      Point2::new(0, 2),
+      Point2::new(1, 2),
+      Point2::new(2, 2),
+    ];
+    let mut boxed = Automaton::new(glider, Neighbourhood::Moore, Rule::life()).with_domain(domain);
+    assert!(matches!(boxed.run_until_stable(), Settled::Stable(_)));
+  }
+
+  #[test]
+  fn test_until_stable() {
+    // A block is a still life; the lone cell next to it dies on the first step.
+    let cells = vec![
+      Point3::new(0, 0, 0),
+      Point3::new(1, 0, 0),
+      Point3::new(0, 1, 0),
+      Point3::new(1, 1, 0),
+      Point3::new(5, 5, 5),
+    ];
+    let mut automaton = Automaton::new(cells, Neighbourhood::VonNeumann, Rule::new(&[], &[2]));
+    assert_eq!(automaton.run_until_stable(), Settled::Stable(1));
+    assert_eq!(automaton.count(), 4);
+    assert_eq!(automaton.generation(), 2);
+  }
+
+  #[test]
+  fn test_line_of_sight() {
+    let domain = vec![Point2::new(0, 0), Point2::new(3, 0), Point2::new(3, 3)];
+    let automaton =
+      Automaton::new(domain.clone(), Neighbourhood::LineOfSight, Rule::life()).with_domain(domain);
+    assert_eq!(automaton.active_neighbours(Point2::new(0, 0)), 2);
+    assert_eq!(automaton.active_neighbours(Point2::new(3, 0)), 2);
+    assert_eq!(automaton.active_neighbours(Point2::new(1, 1)), 2);
+  }
+
+  #[test]
+  fn test_domain_births_from_zero() {
+    let domain = vec![Point2::new(0, 0), Point2::new(1, 0), Point2::new(5, 0)];
+    let mut automaton =
+      Automaton::new(vec![], Neighbourhood::Moore, Rule::new(&[0], &[0])).with_domain(domain);
+    automaton.step();
+    assert_eq!(automaton.count(), 3);
+  }
+}
@@ -0,0 +1,517 @@
+use std::env;
+use std::process;
+use std::time::Duration;
+
+use common::answers::{self, AnswerStore, Check};
+use common::config::{self, Config};
+use common::history::{self, Baseline, Entry, History};
+use common::io;
+use common::scaffold;
+use common::solution::{self, Part, Puzzle, PuzzleId, Registry};
+use common::timing::BenchmarkOptions;
+
+const USAGE: &str = "usage:
+  aoc run <year> [<day>] [--part <1|2>] [--offline]
+  aoc run --all [--part <1|2>] [--offline]
+  aoc run <year> <day> --dot [--offline]
+  aoc run <year> <day> --explain [--offline]
+  aoc bench <year> [<day>] [--part <1|2>] [--offline] [--compare [--baseline <commit>]]
+  aoc bench --all [--part <1|2>] [--offline] [--compare [--baseline <commit>]]
+  aoc new <year> <day>
+  aoc submit <year> <day> <1|2> [<answer>]
+  aoc verify [<year> [<day>] | --all] [--offline] [--record]";
+
+#[derive(Debug, Eq, PartialEq)]
+enum Selection {
+  All,
+  Year(u16),
+  Day(PuzzleId),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct RunArgs {
+  selection: Selection,
+  part: Option<Part>,
+  offline: bool,
+  dot: bool,
+  explain: bool,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+  let mut all = false;
+  let mut part = None;
+  let mut offline = false;
+  let mut dot = false;
+  let mut explain = false;
+  let mut positional = vec![];
+  let mut args = args.iter();
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--all" => all = true,
+      "--offline" => offline = true,
+      "--dot" => dot = true,
+      "--explain" => explain = true,
+      "--part" => {
+        let value = args.next().ok_or("--part requires a value")?;
+        part = Some(value.parse()?);
+      }
+      _ if arg.starts_with("--") => return Err(format!("unknown option '{}'", arg)),
+      _ => positional.push(arg),
+    }
+  }
+
+  if dot && explain {
+    return Err("--dot and --explain cannot be combined".to_string());
+  }
+  let selection = match (all, positional.as_slice()) {
+    (true, []) => Selection::All,
+    (true, _) => return Err("--all does not take a year or day".to_string()),
+    (false, [year]) => Selection::Year(parse_number(year, "year")?),
+    (false, [year, day]) => Selection::Day(PuzzleId::new(
+      parse_number(year, "year")?,
+      parse_number(day, "day")?,
+    )),
+    (false, []) => return Err("expected a year, a year and a day, or --all".to_string()),
+    (false, _) => return Err("too many arguments".to_string()),
+  };
+  Ok(RunArgs {
+    selection,
+    part,
+    offline,
+    dot,
+    explain,
+  })
+}
+
+fn parse_number<T: std::str::FromStr>(s: &str, what: &str) -> Result<T, String> {
+  s.parse().map_err(|_| format!("invalid {} '{}'", what, s))
+}
+
+fn select<'a>(
+  registry: &'a Registry,
+  selection: &Selection,
+) -> Result<Vec<&'a dyn Puzzle>, String> {
+  let puzzles: Vec<&dyn Puzzle> = match selection {
+    Selection::All => registry.iter().collect(),
+    Selection::Year(year) => registry.year(*year).collect(),
+    Selection::Day(id) => registry.get(*id).into_iter().collect(),
+  };
+  if puzzles.is_empty() {
+    return Err(match selection {
+      Selection::All => "no puzzles registered".to_string(),
+      Selection::Year(year) => format!("no puzzles registered for {}", year),
+      Selection::Day(id) => format!("no puzzle registered for {}", id),
+    });
+  }
+  Ok(puzzles)
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+  let args = parse_run_args(args)?;
+  let mut config = Config::load().map_err(|e| e.to_string())?;
+  config.offline |= args.offline;
+  let registry = solution::registry();
+  if args.dot || args.explain {
+    let flag = if args.dot { "--dot" } else { "--explain" };
+    return match args.selection {
+      Selection::Day(id) => view(select(&registry, &args.selection)?[0], &config, args.dot)
+        .map_err(|e| format!("{}: {}", id, e)),
+      _ => Err(format!("{} needs a single day", flag)),
+    };
+  }
+  let puzzles = select(&registry, &args.selection)?;
+
+  let mut total = Duration::default();
+  let mut failed = 0;
+  for puzzle in &puzzles {
+    match solution::run(*puzzle, &config, args.part) {
+      Ok(report) => {
+        total += report.elapsed();
+        println!("{}", report);
+      }
+      Err(e) => {
+        failed += 1;
+        println!("{}\n{}", puzzle.id(), e);
+      }
+    }
+    println!();
+  }
+  if puzzles.len() > 1 {
+    println!("{} puzzles in {:?}", puzzles.len() - failed, total);
+  }
+  if failed > 0 {
+    return Err(format!("{} of {} puzzles failed", failed, puzzles.len()));
+  }
+  Ok(())
+}
+
+/// Prints the puzzle's Graphviz view of its input, or otherwise its explanation of the answers.
+fn view(puzzle: &dyn Puzzle, config: &Config, dot: bool) -> Result<(), String> {
+  let input = puzzle.read_input(config).map_err(|e| e.to_string())?;
+  let view = if dot {
+    puzzle.dot(&input).ok_or("no DOT output for this puzzle")?
+  } else {
+    puzzle
+      .explain(&input)
+      .ok_or("no explanation for this puzzle")?
+  };
+  println!("{}", view);
+  Ok(())
+}
+
+/// Splits `--compare` and `--baseline <commit>` off the `aoc bench` arguments. Comparing
+/// without a pinned commit measures against the previous run.
+fn parse_bench_args(args: &[String]) -> Result<(RunArgs, Option<Baseline>), String> {
+  let mut compare = false;
+  let mut commit = None;
+  let mut rest = vec![];
+  let mut args = args.iter();
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--compare" => compare = true,
+      "--baseline" => commit = Some(args.next().ok_or("--baseline requires a commit")?.clone()),
+      _ => rest.push(arg.clone()),
+    }
+  }
+  let baseline = match (compare, commit) {
+    (false, None) => None,
+    (false, Some(_)) => return Err("--baseline needs --compare".to_string()),
+    (true, None) => Some(Baseline::Previous),
+    (true, Some(commit)) => Some(Baseline::Commit(commit)),
+  };
+  let args = parse_run_args(&rest)?;
+  if args.dot || args.explain {
+    return Err(format!("bench only times the answers\n{}", USAGE));
+  }
+  Ok((args, baseline))
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+  let (args, baseline) = parse_bench_args(args)?;
+  let mut config = Config::load().map_err(|e| e.to_string())?;
+  config.offline |= args.offline;
+  let registry = solution::registry();
+  let options = BenchmarkOptions::default();
+  let mut history = History::load(&config.bench_history).map_err(|e| e.to_string())?;
+  let (commit, machine) = (history::current_commit(), history::machine());
+
+  let mut failed = 0;
+  let mut slowdowns = 0;
+  let mut entries = vec![];
+  for puzzle in select(&registry, &args.selection)? {
+    let input = match puzzle.read_input(&config) {
+      Ok(input) => input,
+      Err(e) => {
+        failed += 1;
+        println!("{}\n{}\n", puzzle.id(), e);
+        continue;
+      }
+    };
+    let report = puzzle.bench(&input, args.part, &options);
+    println!("{}", report);
+    for entry in Entry::from_report(&report, &commit, &machine) {
+      if let Some(pinned) = &baseline {
+        if let Some(baseline) = history.baseline(&entry, pinned) {
+          let comparison = history::compare(baseline, &entry);
+          if comparison.is_slowdown() {
+            slowdowns += 1;
+            println!(
+              "SLOWER {} {:+.1}% vs {} (t = {:.1})",
+              entry.stage,
+              comparison.change * 100.0,
+              baseline.commit,
+              comparison.t
+            );
+          }
+        }
+      }
+      entries.push(entry);
+    }
+    println!();
+  }
+
+  for entry in entries {
+    history.push(entry);
+  }
+  history.save().map_err(|e| e.to_string())?;
+
+  if failed > 0 {
+    return Err(format!("{} puzzles failed", failed));
+  }
+  if slowdowns > 0 {
+    return Err(format!("{} significant slowdowns", slowdowns));
+  }
+  Ok(())
+}
+
+fn new(args: &[String]) -> Result<(), String> {
+  let id = match args {
+    [year, day] => PuzzleId::new(parse_number(year, "year")?, parse_number(day, "day")?),
+    _ => return Err(USAGE.to_string()),
+  };
+  let config = Config::load().map_err(|e| e.to_string())?;
+
+  let description = config.input_dir.join(format!("{}.html", id));
+  let examples = match std::fs::read_to_string(&description) {
+    Ok(html) => scaffold::extract_examples(&html),
+    Err(_) => {
+      println!(
+        "no puzzle description at {}, leaving TEST_INPUT empty",
+        description.display()
+      );
+      vec![]
+    }
+  };
+  let path =
+    scaffold::create_day(config::crate_root(), id, &examples).map_err(|e| e.to_string())?;
+  println!(
+    "created {} with {} example(s)",
+    path.display(),
+    examples.len()
+  );
+
+  match io::read_input_with(&config, &id.to_string()) {
+    Ok(_) => println!(
+      "input cached in {}",
+      config.input_dir.join(id.to_string()).display()
+    ),
+    Err(e) => println!("input not cached: {}", e),
+  }
+  Ok(())
+}
+
+fn submit(args: &[String]) -> Result<(), String> {
+  let (year, day, part, answer) = match args {
+    [year, day, part] => (year, day, part, None),
+    [year, day, part, answer] => (year, day, part, Some(answer.clone())),
+    _ => return Err(USAGE.to_string()),
+  };
+  let id = PuzzleId::new(parse_number(year, "year")?, parse_number(day, "day")?);
+  let part: Part = part.parse()?;
+  let config = Config::load().map_err(|e| e.to_string())?;
+
+  let answer = match answer {
+    Some(answer) => answer,
+    None => {
+      let registry = solution::registry();
+      let puzzle = registry
+        .get(id)
+        .ok_or_else(|| format!("no puzzle registered for {}", id))?;
+      let report = solution::run(puzzle, &config, Some(part)).map_err(|e| e.to_string())?;
+      let answer = match part {
+        Part::One => report.part_one,
+        Part::Two => report.part_two,
+      };
+      answer.expect("requested part was run").value
+    }
+  };
+
+  println!("submitting {} for {} {}", answer, id, part);
+  let verdict =
+    io::submit_answer_with(&config, id.year, id.day, part, &answer).map_err(|e| e.to_string())?;
+  println!("{}", verdict);
+  if verdict == io::Verdict::Correct {
+    AnswerStore::new(&config.answers_dir)
+      .set(id, part, &answer)
+      .map_err(|e| e.to_string())?;
+  }
+  Ok(())
+}
+
+/// The puzzles to verify and whether to record missing answers. Verification always runs on
+/// cached input, so `--offline` is accepted but changes nothing.
+fn parse_verify_args(args: &[String]) -> Result<(Selection, bool), String> {
+  let record = args.iter().any(|a| a == "--record");
+  let args: Vec<String> = args
+    .iter()
+    .filter(|a| *a != "--record" && *a != "--offline")
+    .cloned()
+    .collect();
+  if args.is_empty() {
+    return Ok((Selection::All, record));
+  }
+  match parse_run_args(&args)? {
+    RunArgs {
+      selection,
+      part: None,
+      dot: false,
+      explain: false,
+      ..
+    } => Ok((selection, record)),
+    _ => Err(format!(
+      "verify checks both parts and takes no other options\n{}",
+      USAGE
+    )),
+  }
+}
+
+fn verify(args: &[String]) -> Result<(), String> {
+  let (selection, record) = parse_verify_args(args)?;
+  let config = Config::load().map_err(|e| e.to_string())?;
+  let store = AnswerStore::new(&config.answers_dir);
+  let registry = solution::registry();
+
+  let (mut passed, mut failed, mut missing) = (0, 0, 0);
+  for puzzle in select(&registry, &selection)? {
+    let checks = match answers::verify(puzzle, &config, &store) {
+      Ok(checks) => checks,
+      Err(e) => {
+        failed += 1;
+        println!("{} {}", puzzle.id(), e);
+        continue;
+      }
+    };
+    for (part, check) in checks {
+      println!("{} {} {}", puzzle.id(), part, check);
+      match check {
+        Check::Pass => passed += 1,
+        Check::Fail { .. } => failed += 1,
+        Check::Missing { actual } => {
+          missing += 1;
+          if record {
+            store
+              .set(puzzle.id(), part, &actual)
+              .map_err(|e| e.to_string())?;
+          }
+        }
+      }
+    }
+  }
+  println!("{} passed, {} failed, {} missing", passed, failed, missing);
+  if failed > 0 {
+    return Err(format!("{} checks failed", failed));
+  }
+  Ok(())
+}
+
+fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+  let result = match args.first().map(String::as_str) {
+    Some("run") => run(&args[1..]),
+    Some("bench") => bench(&args[1..]),
+    Some("new") => new(&args[1..]),
+    Some("submit") => submit(&args[1..]),
+    Some("verify") => verify(&args[1..]),
+    _ => Err(USAGE.to_string()),
+  };
+  if let Err(e) = result {
+    eprintln!("{}", e);
+    process::exit(1);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn args(s: &str) -> Vec<String> {
+    s.split_whitespace().map(String::from).collect()
+  }
+
+  #[test]
+  fn test_parse_run_args() {
+    assert_eq!(
+      parse_run_args(&args("2020 11")),
+      Ok(RunArgs {
+        selection: Selection::Day(PuzzleId::new(2020, 11)),
+        part: None,
+        offline: false,
+        dot: false,
+        explain: false
+      })
+    );
+    assert_eq!(
+      parse_run_args(&args("2020 --part 2 --offline")),
+      Ok(RunArgs {
+        selection: Selection::Year(2020),
+        part: Some(Part::Two),
+        offline: true,
+        dot: false,
+        explain: false
+      })
+    );
+    assert_eq!(
+      parse_run_args(&args("--all")),
+      Ok(RunArgs {
+        selection: Selection::All,
+        part: None,
+        offline: false,
+        dot: false,
+        explain: false
+      })
+    );
+    assert_eq!(
+      parse_run_args(&args("2015 7 --dot")),
+      Ok(RunArgs {
+        selection: Selection::Day(PuzzleId::new(2015, 7)),
+        part: None,
+        offline: false,
+        dot: true,
+        explain: false
+      })
+    );
+    assert_eq!(
+      parse_run_args(&args("2020 8 --explain")),
+      Ok(RunArgs {
+        selection: Selection::Day(PuzzleId::new(2020, 8)),
+        part: None,
+        offline: false,
+        dot: false,
+        explain: true
+      })
+    );
+    assert!(parse_run_args(&args("2020 8 --dot --explain")).is_err());
+    assert!(parse_run_args(&args("")).is_err());
+    assert!(parse_run_args(&args("--all 2020")).is_err());
+    assert!(parse_run_args(&args("2020 1 --part 3")).is_err());
+    assert!(parse_run_args(&args("2020 x")).is_err());
+  }
+
+  #[test]
+  fn test_parse_verify_args() {
+    assert_eq!(parse_verify_args(&args("")), Ok((Selection::All, false)));
+    assert_eq!(
+      parse_verify_args(&args("--offline")),
+      Ok((Selection::All, false))
+    );
+    assert_eq!(
+      parse_verify_args(&args("2020 --offline --record")),
+      Ok((Selection::Year(2020), true))
+    );
+    assert_eq!(
+      parse_verify_args(&args("2020 8")),
+      Ok((Selection::Day(PuzzleId::new(2020, 8)), false))
+    );
+    assert!(parse_verify_args(&args("2020 8 --part 1")).is_err());
+    assert!(parse_verify_args(&args("2020 8 --dot")).is_err());
+    assert!(parse_verify_args(&args("--bogus")).is_err());
+  }
+
+  #[test]
+  fn test_parse_bench_args() {
+    let (run_args, baseline) = parse_bench_args(&args("2020 11 --compare")).unwrap();
+    assert_eq!(run_args.selection, Selection::Day(PuzzleId::new(2020, 11)));
+    assert_eq!(baseline, Some(Baseline::Previous));
+    assert_eq!(
+      parse_bench_args(&args("--all --compare --baseline abc123"))
+        .unwrap()
+        .1,
+      Some(Baseline::Commit("abc123".to_string()))
+    );
+    assert_eq!(parse_bench_args(&args("2020")).unwrap().1, None);
+    assert!(parse_bench_args(&args("2020 --baseline abc123")).is_err());
+    assert!(parse_bench_args(&args("2020 --compare --baseline")).is_err());
+    assert!(parse_bench_args(&args("2020 8 --dot")).is_err());
+    assert!(parse_bench_args(&args("2020 8 --explain --compare")).is_err());
+  }
+
+  #[test]
+  fn test_select() {
+    let registry = solution::registry();
+    let year = select(&registry, &Selection::Year(2016)).unwrap();
+    assert!(year.iter().any(|p| p.id() == PuzzleId::new(2016, 1)));
+    assert!(year.iter().all(|p| p.id().year == 2016));
+    assert!(select(&registry, &Selection::Year(2014)).is_err());
+    assert!(select(&registry, &Selection::Day(PuzzleId::new(2014, 1))).is_err());
+  }
+}
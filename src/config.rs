@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::io::InputError;
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const OFFLINE_VAR: &str = "AOC_OFFLINE";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const ANSWERS_DIR_VAR: &str = "AOC_ANSWERS_DIR";
+pub const BENCH_HISTORY_VAR: &str = "AOC_BENCH_HISTORY";
+pub const CONFIG_FILE_VAR: &str = "AOC_CONFIG";
+
+const DEFAULT_INPUT_DIR: &str = "inputs";
+const DEFAULT_SESSION_FILE: &str = ".session_cookie";
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_ANSWERS_DIR: &str = "answers";
+const DEFAULT_BENCH_HISTORY: &str = "bench_history.json";
+const KEYS: [&str; 6] = [
+  "input_dir",
+  "session",
+  "offline",
+  "base_url",
+  "answers_dir",
+  "bench_history",
+];
+
+/// Where inputs live and how to authenticate, layered as environment variables,
+/// then the user config file, then the defaults relative to the crate root.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Config {
+  pub input_dir: PathBuf,
+  pub session: Option<String>,
+  /// Never touch the network; a missing input is an error instead of a download.
+  pub offline: bool,
+  pub base_url: String,
+  pub answers_dir: PathBuf,
+  pub bench_history: PathBuf,
+}
+
+impl Config {
+  pub fn load() -> Result<Config, InputError> {
+    let file = match config_file_path() {
+      Some(path) => read_optional(&path)?,
+      None => None,
+    };
+    let default_session = read_optional(&crate_root().join(DEFAULT_SESSION_FILE))?;
+    Config::from_sources(
+      |key| env::var(key).ok(),
+      file.as_deref(),
+      default_session.as_deref(),
+      crate_root(),
+    )
+  }
+
+  pub fn from_sources(
+    env: impl Fn(&str) -> Option<String>,
+    file: Option<&str>,
+    default_session: Option<&str>,
+    root: &Path,
+  ) -> Result<Config, InputError> {
+    let file = match file {
+      Some(contents) => parse_config_file(contents)?,
+      None => HashMap::new(),
+    };
+    let lookup = |var: &str, key: &str| env(var).or_else(|| file.get(key).cloned());
+
+    let input_dir =
+      lookup(INPUT_DIR_VAR, "input_dir").unwrap_or_else(|| DEFAULT_INPUT_DIR.to_string());
+    let session = lookup(SESSION_VAR, "session")
+      .or_else(|| default_session.map(String::from))
+      .map(|s| s.trim().to_string())
+      .filter(|s| !s.is_empty());
+    let offline = match lookup(OFFLINE_VAR, "offline") {
+      Some(value) => parse_bool(&value)?,
+      None => false,
+    };
+    let base_url = lookup(BASE_URL_VAR, "base_url").unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+    let answers_dir =
+      lookup(ANSWERS_DIR_VAR, "answers_dir").unwrap_or_else(|| DEFAULT_ANSWERS_DIR.to_string());
+    let bench_history = lookup(BENCH_HISTORY_VAR, "bench_history")
+      .unwrap_or_else(|| DEFAULT_BENCH_HISTORY.to_string());
+
+    Ok(Config {
+      input_dir: root.join(input_dir),
+      session,
+      offline,
+      base_url: base_url.trim_end_matches('/').to_string(),
+      answers_dir: root.join(answers_dir),
+      bench_history: root.join(bench_history),
+    })
+  }
+
+  pub fn ledger_path(&self) -> PathBuf {
+    self.input_dir.join("answers.ledger")
+  }
+}
+
+pub fn crate_root() -> &'static Path {
+  Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn config_file_path() -> Option<PathBuf> {
+  if let Some(path) = env::var_os(CONFIG_FILE_VAR) {
+    return Some(PathBuf::from(path));
+  }
+  let config_dir = env::var_os("XDG_CONFIG_HOME")
+    .map(PathBuf::from)
+    .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+    .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+  Some(config_dir.join("advent-of-rust").join("config"))
+}
+
+fn parse_bool(value: &str) -> Result<bool, InputError> {
+  match value.trim().to_lowercase().as_str() {
+    "1" | "true" | "yes" | "on" => Ok(true),
+    "0" | "false" | "no" | "off" | "" => Ok(false),
+    _ => Err(InputError::InvalidConfig(format!(
+      "expected a boolean, got '{}'",
+      value
+    ))),
+  }
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>, InputError> {
+  match fs::read_to_string(path) {
+    Ok(contents) => Ok(Some(contents)),
+    Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+    Err(e) => Err(e.into()),
+  }
+}
+
+fn parse_config_file(contents: &str) -> Result<HashMap<String, String>, InputError> {
+  let mut values = HashMap::new();
+  for (i, line) in contents.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    let (key, value) = match line.find('=') {
+      Some(pos) => (line[..pos].trim(), line[pos + 1..].trim()),
+      None => {
+        return Err(InputError::InvalidConfig(format!(
+          "line {}: expected key = value",
+          i + 1
+        )))
+      }
+    };
+    if !KEYS.contains(&key) {
+      return Err(InputError::InvalidConfig(format!(
+        "line {}: unknown key '{}'",
+        i + 1,
+        key
+      )));
+    }
+    values.insert(key.to_string(), value.to_string());
+  }
+  Ok(values)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn no_env(_: &str) -> Option<String> {
+    None
+  }
+
+  #[test]
+  fn test_defaults() {
+    let root = Path::new("/repo");
+    let config = Config::from_sources(no_env, None, None, root).unwrap();
+    assert_eq!(config.input_dir, PathBuf::from("/repo/inputs"));
+    assert_eq!(config.session, None);
+    assert!(!config.offline);
+    assert_eq!(config.base_url, "https://adventofcode.com");
+    assert_eq!(config.answers_dir, PathBuf::from("/repo/answers"));
+    assert_eq!(
+      config.bench_history,
+      PathBuf::from("/repo/bench_history.json")
+    );
+
+    let config = Config::from_sources(no_env, None, Some("abc\n"), root).unwrap();
+    assert_eq!(config.session, Some("abc".to_string()));
+  }
+
+  #[test]
+  fn test_layering() {
+    let root = Path::new("/repo");
+    let file = "# comment\ninput_dir = data\nsession = from-file\noffline = true\n";
+    let config = Config::from_sources(no_env, Some(file), Some("from-default"), root).unwrap();
+    assert_eq!(config.input_dir, PathBuf::from("/repo/data"));
+    assert_eq!(config.session, Some("from-file".to_string()));
+    assert!(config.offline);
+
+    let env = |key: &str| match key {
+      INPUT_DIR_VAR => Some("/abs/inputs".to_string()),
+      SESSION_VAR => Some("from-env".to_string()),
+      OFFLINE_VAR => Some("0".to_string()),
+      BASE_URL_VAR => Some("http://127.0.0.1:8080/".to_string()),
+      _ => None,
+    };
+    let config = Config::from_sources(env, Some(file), Some("from-default"), root).unwrap();
+    assert_eq!(config.input_dir, PathBuf::from("/abs/inputs"));
+    assert_eq!(config.session, Some("from-env".to_string()));
+    assert!(!config.offline);
+    assert_eq!(config.base_url, "http://127.0.0.1:8080");
+  }
+
+  #[test]
+  fn test_invalid_config_file() {
+    let root = Path::new("/repo");
+    assert!(matches!(
+      Config::from_sources(no_env, Some("input_dir"), None, root),
+      Err(InputError::InvalidConfig(_))
+    ));
+    assert!(matches!(
+      Config::from_sources(no_env, Some("colour = blue"), None, root),
+      Err(InputError::InvalidConfig(_))
+    ));
+    assert!(matches!(
+      Config::from_sources(no_env, Some("offline = maybe"), None, root),
+      Err(InputError::InvalidConfig(_))
+    ));
+  }
+}
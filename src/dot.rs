@@ -0,0 +1,172 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// Attributes added to everything on a highlighted path.
+const HIGHLIGHT: [(&str, &str); 2] = [("color", "red"), ("penwidth", "2")];
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Node {
+  id: String,
+  attrs: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Edge {
+  from: String,
+  to: String,
+  attrs: Vec<(String, String)>,
+}
+
+/// A directed graph written in Graphviz DOT, for looking at puzzle inputs with `dot -Tsvg`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Graph {
+  name: String,
+  nodes: Vec<Node>,
+  edges: Vec<Edge>,
+}
+
+fn attrs(attrs: &[(&str, &str)]) -> Vec<(String, String)> {
+  attrs
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect()
+}
+
+/// Quotes an ID so wire names like `if` or labels with spaces are safe. Backslashes are left
+/// alone so labels can use DOT escapes like `\n`.
+fn quote(s: &str) -> String {
+  format!("\"{}\"", s.replace('"', "\\\""))
+}
+
+fn write_attrs(f: &mut Formatter<'_>, attrs: &[(String, String)]) -> fmt::Result {
+  if attrs.is_empty() {
+    return Ok(());
+  }
+  write!(f, " [")?;
+  for (i, (k, v)) in attrs.iter().enumerate() {
+    if i > 0 {
+      write!(f, ", ")?;
+    }
+    write!(f, "{}={}", k, quote(v))?;
+  }
+  write!(f, "]")
+}
+
+impl Graph {
+  pub fn new(name: &str) -> Graph {
+    Graph {
+      name: name.to_string(),
+      nodes: vec![],
+      edges: vec![],
+    }
+  }
+
+  /// Adds a node, or more attributes to a node that is already there.
+  pub fn node(&mut self, id: &str, node_attrs: &[(&str, &str)]) -> &mut Graph {
+    match self.nodes.iter_mut().find(|n| n.id == id) {
+      Some(node) => node.attrs.extend(attrs(node_attrs)),
+      None => self.nodes.push(Node {
+        id: id.to_string(),
+        attrs: attrs(node_attrs),
+      }),
+    }
+    self
+  }
+
+  pub fn edge(&mut self, from: &str, to: &str, edge_attrs: &[(&str, &str)]) -> &mut Graph {
+    self.edges.push(Edge {
+      from: from.to_string(),
+      to: to.to_string(),
+      attrs: attrs(edge_attrs),
+    });
+    self
+  }
+
+  /// Every node with a path to `target`, including `target` itself.
+  pub fn ancestors(&self, target: &str) -> HashSet<String> {
+    let mut sources: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in &self.edges {
+      sources.entry(&edge.to).or_default().push(&edge.from);
+    }
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut stack = vec![target];
+    while let Some(id) = stack.pop() {
+      if seen.insert(id.to_string()) {
+        stack.extend(sources.get(id).into_iter().flatten());
+      }
+    }
+    seen
+  }
+
+  /// Colours `target` and every node and edge on a path leading to it.
+  pub fn highlight_paths_to(&mut self, target: &str) -> &mut Graph {
+    let on_path = self.ancestors(target);
+    for id in &on_path {
+      self.node(id, &HIGHLIGHT);
+    }
+    for edge in self.edges.iter_mut() {
+      if on_path.contains(&edge.from) && on_path.contains(&edge.to) {
+        edge.attrs.extend(attrs(&HIGHLIGHT));
+      }
+    }
+    self
+  }
+}
+
+impl Display for Graph {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(f, "digraph {} {{", quote(&self.name))?;
+    for node in &self.nodes {
+      write!(f, "  {}", quote(&node.id))?;
+      write_attrs(f, &node.attrs)?;
+      writeln!(f, ";")?;
+    }
+    for edge in &self.edges {
+      write!(f, "  {} -> {}", quote(&edge.from), quote(&edge.to))?;
+      write_attrs(f, &edge.attrs)?;
+      writeln!(f, ";")?;
+    }
+    write!(f, "}}")
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_display() {
+    let mut graph = Graph::new("bags");
+    graph
+      .node("a", &[("label", "say \"hi\"")])
+      .node("b", &[])
+      .edge("a", "b", &[("label", "2")]);
+    assert_eq!(
+      graph.to_string(),
+      "digraph \"bags\" {
+  \"a\" [label=\"say \\\"hi\\\"\"];
+  \"b\";
+  \"a\" -> \"b\" [label=\"2\"];
+}"
+    );
+  }
+
+  #[test]
+  fn test_highlight_paths_to() {
+    let mut graph = Graph::new("g");
+    graph
+      .edge("a", "b", &[])
+      .edge("b", "c", &[])
+      .edge("c", "d", &[])
+      .edge("x", "d", &[]);
+    let mut ancestors: Vec<String> = graph.ancestors("c").into_iter().collect();
+    ancestors.sort();
+    assert_eq!(ancestors, vec!["a", "b", "c"]);
+    let dot = graph.highlight_paths_to("c").to_string();
+    assert!(dot.contains("\"b\" -> \"c\" [color=\"red\", penwidth=\"2\"];"));
+    assert!(dot.contains("\"c\" -> \"d\";"));
+    assert!(dot.contains("\"a\" [color=\"red\", penwidth=\"2\"];"));
+    assert!(!dot.contains("\"x\" ["));
+  }
+}
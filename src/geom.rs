@@ -0,0 +1,282 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::grid::Pos;
+
+/// A point with `N` integer coordinates. In 2D `y` grows downwards, so north is `-y`
+/// like in the puzzle text and in [`crate::grid::Grid`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [i64; N]);
+
+pub type Point2 = Point<2>;
+pub type Point3 = Point<3>;
+pub type Point4 = Point<4>;
+
+impl<const N: usize> Point<N> {
+  pub fn origin() -> Point<N> {
+    Point([0; N])
+  }
+
+  /// Places a grid position in the `x`/`y` plane, with every other coordinate 0.
+  pub fn embed((x, y): Pos) -> Point<N> {
+    let mut coords = [0; N];
+    coords[0] = x as i64;
+    coords[1] = y as i64;
+    Point(coords)
+  }
+
+  pub fn manhattan(&self, other: &Point<N>) -> i64 {
+    (*self - *other).0.iter().map(|c| c.abs()).sum()
+  }
+
+  fn zip(self, other: Point<N>, f: impl Fn(i64, i64) -> i64) -> Point<N> {
+    Point(std::array::from_fn(|i| f(self.0[i], other.0[i])))
+  }
+}
+
+impl Point2 {
+  pub fn new(x: i64, y: i64) -> Point2 {
+    Point([x, y])
+  }
+
+  pub fn x(&self) -> i64 {
+    self.0[0]
+  }
+
+  pub fn y(&self) -> i64 {
+    self.0[1]
+  }
+
+  /// Rotates `quarters` quarter turns about the origin.
+  pub fn rotate(&self, turn: Turn, quarters: i64) -> Point2 {
+    let quarters = match turn {
+      Turn::Right => quarters,
+      Turn::Left => -quarters,
+    };
+    let (x, y) = (self.x(), self.y());
+    match quarters.rem_euclid(4) {
+      1 => Point2::new(-y, x),
+      2 => Point2::new(-x, -y),
+      3 => Point2::new(y, -x),
+      _ => *self,
+    }
+  }
+}
+
+impl Point3 {
+  pub fn new(x: i64, y: i64, z: i64) -> Point3 {
+    Point([x, y, z])
+  }
+}
+
+impl Point4 {
+  pub fn new(x: i64, y: i64, z: i64, w: i64) -> Point4 {
+    Point([x, y, z, w])
+  }
+}
+
+impl<const N: usize> Add for Point<N> {
+  type Output = Point<N>;
+
+  fn add(self, other: Point<N>) -> Point<N> {
+    self.zip(other, |a, b| a + b)
+  }
+}
+
+impl<const N: usize> Sub for Point<N> {
+  type Output = Point<N>;
+
+  fn sub(self, other: Point<N>) -> Point<N> {
+    self.zip(other, |a, b| a - b)
+  }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+  fn add_assign(&mut self, other: Point<N>) {
+    *self = *self + other;
+  }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+  fn sub_assign(&mut self, other: Point<N>) {
+    *self = *self - other;
+  }
+}
+
+impl<const N: usize> Mul<i64> for Point<N> {
+  type Output = Point<N>;
+
+  fn mul(self, factor: i64) -> Point<N> {
+    Point(self.0.map(|c| c * factor))
+  }
+}
+
+impl<const N: usize> Neg for Point<N> {
+  type Output = Point<N>;
+
+  fn neg(self) -> Point<N> {
+    self * -1
+  }
+}
+
+impl<const N: usize> Display for Point<N> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "(")?;
+    for (i, c) in self.0.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      write!(f, "{}", c)?;
+    }
+    write!(f, ")")
+  }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Turn {
+  Left,
+  Right,
+}
+
+/// A compass direction.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Heading {
+  North,
+  East,
+  South,
+  West,
+}
+
+const HEADINGS: [Heading; 4] = [Heading::North, Heading::East, Heading::South, Heading::West];
+
+impl Heading {
+  /// Accepts `NESW` as well as the arrows `^>v<`.
+  pub fn from_char(c: char) -> Option<Heading> {
+    match c {
+      'N' | '^' => Some(Heading::North),
+      'E' | '>' => Some(Heading::East),
+      'S' | 'v' => Some(Heading::South),
+      'W' | '<' => Some(Heading::West),
+      _ => None,
+    }
+  }
+
+  /// The unit step in this direction.
+  pub fn delta(&self) -> Point2 {
+    match self {
+      Heading::North => Point2::new(0, -1),
+      Heading::East => Point2::new(1, 0),
+      Heading::South => Point2::new(0, 1),
+      Heading::West => Point2::new(-1, 0),
+    }
+  }
+
+  /// Turns `quarters` quarter turns, so `turn(Turn::Right, 2)` faces the other way.
+  pub fn turn(&self, turn: Turn, quarters: i64) -> Heading {
+    let quarters = match turn {
+      Turn::Right => quarters,
+      Turn::Left => -quarters,
+    };
+    HEADINGS[(*self as i64 + quarters).rem_euclid(4) as usize]
+  }
+
+  pub fn right(&self) -> Heading {
+    self.turn(Turn::Right, 1)
+  }
+
+  pub fn left(&self) -> Heading {
+    self.turn(Turn::Left, 1)
+  }
+}
+
+/// A letter and an amount, as in `F10`, `N3`, `R90` or `L3`. What the amount of a turn
+/// means, degrees or steps to walk after turning, is up to the puzzle.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Command {
+  Move(Heading, i64),
+  Turn(Turn, i64),
+  Forward(i64),
+}
+
+/// The number of quarter turns in `degrees`. Panics if it is not a multiple of 90.
+pub fn quarters(degrees: i64) -> i64 {
+  assert_eq!(degrees % 90, 0, "{} degrees is not a quarter turn", degrees);
+  degrees / 90
+}
+
+impl FromStr for Command {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Command, String> {
+    let s = s.trim();
+    let letter = s.chars().next().ok_or("empty command")?;
+    let amount = s[letter.len_utf8()..]
+      .parse()
+      .map_err(|_| format!("invalid command {:?}", s))?;
+    match letter {
+      'L' => Ok(Command::Turn(Turn::Left, amount)),
+      'R' => Ok(Command::Turn(Turn::Right, amount)),
+      'F' => Ok(Command::Forward(amount)),
+      _ => match Heading::from_char(letter) {
+        Some(heading) => Ok(Command::Move(heading, amount)),
+        None => Err(format!("invalid command {:?}", s)),
+      },
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_point_ops() {
+    let a = Point3::new(1, -2, 3);
+    let b = Point3::new(-4, 5, 6);
+    assert_eq!(a + b, Point3::new(-3, 3, 9));
+    assert_eq!(a - b, Point3::new(5, -7, -3));
+    assert_eq!(-a * 2, Point3::new(-2, 4, -6));
+    assert_eq!(a.manhattan(&b), 15);
+    assert_eq!(Point4::new(1, 2, 3, -4).manhattan(&Point4::origin()), 10);
+    assert_eq!(Point2::new(3, -1).to_string(), "(3, -1)");
+    assert_eq!(Point4::embed((2, 5)), Point4::new(2, 5, 0, 0));
+  }
+
+  #[test]
+  fn test_rotate() {
+    let p = Point2::new(10, -4);
+    assert_eq!(p.rotate(Turn::Right, 1), Point2::new(4, 10));
+    assert_eq!(p.rotate(Turn::Left, 3), Point2::new(4, 10));
+    assert_eq!(p.rotate(Turn::Left, 4), p);
+    assert_eq!(p.rotate(Turn::Left, 1), Point2::new(-4, -10));
+    assert_eq!(p.rotate(Turn::Left, 2), Point2::new(-10, 4));
+    assert_eq!(
+      Heading::East.delta().rotate(Turn::Right, 1),
+      Heading::East.right().delta()
+    );
+  }
+
+  #[test]
+  fn test_heading() {
+    assert_eq!(Heading::North.right(), Heading::East);
+    assert_eq!(Heading::North.left(), Heading::West);
+    assert_eq!(Heading::East.turn(Turn::Left, 3), Heading::South);
+    assert_eq!(Heading::West.turn(Turn::Right, 6), Heading::East);
+    assert_eq!(Heading::from_char('v'), Some(Heading::South));
+    assert_eq!(Heading::from_char('x'), None);
+  }
+
+  #[test]
+  fn test_command_from_str() {
+    assert_eq!("R90".parse(), Ok(Command::Turn(Turn::Right, 90)));
+    assert_eq!(" L3".parse(), Ok(Command::Turn(Turn::Left, 3)));
+    assert_eq!("F10".parse(), Ok(Command::Forward(10)));
+    assert_eq!("N3".parse(), Ok(Command::Move(Heading::North, 3)));
+    assert!("X3".parse::<Command>().is_err());
+    assert!("R".parse::<Command>().is_err());
+    assert!("".parse::<Command>().is_err());
+    assert_eq!(quarters(270), 3);
+  }
+}
@@ -0,0 +1,662 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use petgraph::algo::{astar, tarjan_scc, toposort};
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::{Bfs, Dfs, EdgeRef, Reversed};
+use petgraph::Direction;
+
+/// A directed graph with nodes named by strings, for puzzles that describe graphs as lines of
+/// `name -> name` relations. Built on petgraph, with every lookup going through the names.
+#[derive(Debug, Clone)]
+pub struct Digraph<E> {
+  graph: DiGraph<String, E>,
+  index: HashMap<String, NodeIndex>,
+}
+
+impl<E> Default for Digraph<E> {
+  fn default() -> Digraph<E> {
+    Digraph {
+      graph: DiGraph::new(),
+      index: HashMap::new(),
+    }
+  }
+}
+
+impl<E> Digraph<E> {
+  pub fn new() -> Digraph<E> {
+    Digraph::default()
+  }
+
+  pub fn from_edges<S: AsRef<str>>(edges: impl IntoIterator<Item = (S, S, E)>) -> Digraph<E> {
+    let mut graph = Digraph::new();
+    for (from, to, weight) in edges {
+      graph.add_edge(from.as_ref(), to.as_ref(), weight);
+    }
+    graph
+  }
+
+  /// Adds `name` unless it is already there.
+  pub fn add_node(&mut self, name: &str) {
+    self.node(name);
+  }
+
+  fn node(&mut self, name: &str) -> NodeIndex {
+    if let Some(&node) = self.index.get(name) {
+      return node;
+    }
+    let node = self.graph.add_node(name.to_string());
+    self.index.insert(name.to_string(), node);
+    node
+  }
+
+  pub fn add_edge(&mut self, from: &str, to: &str, weight: E) {
+    let (from, to) = (self.node(from), self.node(to));
+    self.graph.add_edge(from, to, weight);
+  }
+
+  pub fn contains(&self, name: &str) -> bool {
+    self.index.contains_key(name)
+  }
+
+  pub fn len(&self) -> usize {
+    self.graph.node_count()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.graph.node_count() == 0
+  }
+
+  fn name(&self, node: NodeIndex) -> &str {
+    &self.graph[node]
+  }
+
+  fn names(&self, nodes: impl IntoIterator<Item = NodeIndex>) -> Vec<&str> {
+    nodes.into_iter().map(|node| self.name(node)).collect()
+  }
+
+  /// Every node, in the order they were added.
+  pub fn nodes(&self) -> impl Iterator<Item = &str> {
+    self.graph.node_indices().map(move |node| self.name(node))
+  }
+
+  fn neighbours(&self, name: &str, direction: Direction) -> Vec<(&str, &E)> {
+    match self.index.get(name) {
+      Some(&node) => self
+        .graph
+        .edges_directed(node, direction)
+        .map(|edge| {
+          let other = match direction {
+            Direction::Outgoing => edge.target(),
+            Direction::Incoming => edge.source(),
+          };
+          (self.name(other), edge.weight())
+        })
+        .collect(),
+      None => vec![],
+    }
+  }
+
+  /// The nodes `name` has edges to, with the edge weights.
+  pub fn successors(&self, name: &str) -> Vec<(&str, &E)> {
+    self.neighbours(name, Direction::Outgoing)
+  }
+
+  /// The nodes with edges to `name`, with the edge weights.
+  pub fn predecessors(&self, name: &str) -> Vec<(&str, &E)> {
+    self.neighbours(name, Direction::Incoming)
+  }
+
+  /// Every node reachable from `start`, including itself, in breadth-first order.
+  pub fn bfs(&self, start: &str) -> Vec<&str> {
+    let mut order = vec![];
+    if let Some(&start) = self.index.get(start) {
+      let mut bfs = Bfs::new(&self.graph, start);
+      while let Some(node) = bfs.next(&self.graph) {
+        order.push(self.name(node));
+      }
+    }
+    order
+  }
+
+  /// Every node reachable from `start`, including itself, in depth-first order.
+  pub fn dfs(&self, start: &str) -> Vec<&str> {
+    let mut order = vec![];
+    if let Some(&start) = self.index.get(start) {
+      let mut dfs = Dfs::new(&self.graph, start);
+      while let Some(node) = dfs.next(&self.graph) {
+        order.push(self.name(node));
+      }
+    }
+    order
+  }
+
+  /// Every node with a path to `target`, including itself.
+  pub fn ancestors(&self, target: &str) -> HashSet<&str> {
+    let mut ancestors = HashSet::new();
+    if let Some(&target) = self.index.get(target) {
+      let reversed = Reversed(&self.graph);
+      let mut bfs = Bfs::new(reversed, target);
+      while let Some(node) = bfs.next(reversed) {
+        ancestors.insert(self.name(node));
+      }
+    }
+    ancestors
+  }
+
+  /// Every node after all the nodes with edges to it. Fails with the nodes that sit on a
+  /// cycle, sorted, when there is no such order.
+  pub fn topological_order(&self) -> Result<Vec<&str>, Vec<String>> {
+    toposort(&self.graph, None)
+      .map(|order| self.names(order))
+      .map_err(|_| self.cycles())
+  }
+
+  /// Every node on a cycle, sorted.
+  pub fn cycles(&self) -> Vec<String> {
+    let mut nodes: Vec<String> = tarjan_scc(&self.graph)
+      .into_iter()
+      .filter(|scc| scc.len() > 1 || self.graph.contains_edge(scc[0], scc[0]))
+      .flatten()
+      .map(|node| self.name(node).to_string())
+      .collect();
+    nodes.sort();
+    nodes
+  }
+
+  /// Computes a value for every node from its successors, given as name, edge and value, each
+  /// node once, so shared sub-graphs are only folded once. Fails like
+  /// [`Digraph::topological_order`] on cycles.
+  pub fn fold_dag<'a, T>(
+    &'a self,
+    mut f: impl FnMut(&'a str, &[(&'a str, &'a E, &T)]) -> T,
+  ) -> Result<HashMap<&'a str, T>, Vec<String>> {
+    let order = toposort(&self.graph, None).map_err(|_| self.cycles())?;
+    let mut values: HashMap<NodeIndex, T> = HashMap::new();
+    for &node in order.iter().rev() {
+      let children: Vec<(&str, &E, &T)> = self
+        .graph
+        .edges_directed(node, Direction::Outgoing)
+        .map(|edge| {
+          (
+            self.name(edge.target()),
+            edge.weight(),
+            &values[&edge.target()],
+          )
+        })
+        .collect();
+      let value = f(self.name(node), &children);
+      values.insert(node, value);
+    }
+    Ok(
+      values
+        .into_iter()
+        .map(|(node, value)| (self.name(node), value))
+        .collect(),
+    )
+  }
+
+  /// The cheapest path from `from` to `to` by A*, with `estimate` a lower bound on the cost
+  /// from a node to `to`. Returns the cost and the nodes along the way.
+  pub fn astar(
+    &self,
+    from: &str,
+    to: &str,
+    cost: impl Fn(&E) -> u64,
+    estimate: impl Fn(&str) -> u64,
+  ) -> Option<(u64, Vec<&str>)> {
+    let (&start, &goal) = (self.index.get(from)?, self.index.get(to)?);
+    astar(
+      &self.graph,
+      start,
+      |node| node == goal,
+      |edge| cost(edge.weight()),
+      |node| estimate(self.name(node)),
+    )
+    .map(|(cost, path)| (cost, self.names(path)))
+  }
+
+  /// [`Digraph::astar`] without an estimate.
+  pub fn dijkstra(
+    &self,
+    from: &str,
+    to: &str,
+    cost: impl Fn(&E) -> u64,
+  ) -> Option<(u64, Vec<&str>)> {
+    self.astar(from, to, cost, |_| 0)
+  }
+
+  /// The node names in insertion order and the weight of the edge between every pair, for
+  /// [`hamiltonian`]. Where there are parallel edges the last one wins.
+  pub fn weight_matrix(&self, weight: impl Fn(&E) -> i64) -> (Vec<&str>, Vec<Vec<Option<i64>>>) {
+    let n = self.len();
+    let mut weights = vec![vec![None; n]; n];
+    for edge in self.graph.edge_references() {
+      weights[edge.source().index()][edge.target().index()] = Some(weight(edge.weight()));
+    }
+    (self.nodes().collect(), weights)
+  }
+}
+
+/// Whether a path has to return to where it started.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Tour {
+  /// Starts and ends anywhere.
+  Open,
+  /// Ends with the edge back to the first node, like a round trip or a round table.
+  Closed,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Objective {
+  Min,
+  Max,
+}
+
+impl Objective {
+  fn better(self, a: i64, b: i64) -> bool {
+    match self {
+      Objective::Min => a < b,
+      Objective::Max => a > b,
+    }
+  }
+}
+
+/// Nodes in visiting order and the total weight of the edges between them.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Path {
+  pub order: Vec<usize>,
+  pub cost: i64,
+}
+
+/// The most nodes [`hamiltonian`] takes. Its tables hold `2^n n` entries, about 80MB at 18
+/// nodes and four times that for every two more.
+pub const HAMILTONIAN_MAX_NODES: usize = 18;
+
+/// The best path visiting every node exactly once, by Held-Karp dynamic programming over
+/// subsets in `O(2^n n^2)`. `weights[a][b]` is the weight of going from `a` to `b`, `None` where
+/// there is no edge. Closed tours always start at node 0. Returns `None` when there are no
+/// nodes or no such path. Panics past [`HAMILTONIAN_MAX_NODES`] nodes, before allocating.
+pub fn hamiltonian(weights: &[Vec<Option<i64>>], tour: Tour, objective: Objective) -> Option<Path> {
+  let n = weights.len();
+  if n == 0 {
+    return None;
+  }
+  assert!(
+    n <= HAMILTONIAN_MAX_NODES,
+    "{} nodes is too many for Held-Karp, at most {}",
+    n,
+    HAMILTONIAN_MAX_NODES
+  );
+  let full = (1usize << n) - 1;
+  let at = |mask: usize, last: usize| mask * n + last;
+
+  // best[at(mask, last)]: the best cost of visiting exactly `mask`, ending at `last`.
+  let mut best: Vec<Option<i64>> = vec![None; n << n];
+  let mut parent: Vec<u8> = vec![0; n << n];
+  match tour {
+    Tour::Open => (0..n).for_each(|start| best[at(1 << start, start)] = Some(0)),
+    Tour::Closed => best[at(1, 0)] = Some(0),
+  }
+  for mask in 1..full {
+    for last in 0..n {
+      let cost = match best[at(mask, last)] {
+        Some(cost) => cost,
+        None => continue,
+      };
+      for next in (0..n).filter(|next| mask & (1 << next) == 0) {
+        let weight = match weights[last][next] {
+          Some(weight) => weight,
+          None => continue,
+        };
+        let (extended, cost) = (mask | (1 << next), cost + weight);
+        if best[at(extended, next)].is_none_or(|b| objective.better(cost, b)) {
+          best[at(extended, next)] = Some(cost);
+          parent[at(extended, next)] = last as u8;
+        }
+      }
+    }
+  }
+
+  let mut end: Option<(usize, i64)> = None;
+  for last in 0..n {
+    let cost = match (best[at(full, last)], tour) {
+      (Some(cost), Tour::Closed) if n > 1 => weights[last][0].map(|w| cost + w),
+      (cost, _) => cost,
+    };
+    if let Some(cost) = cost {
+      if end.is_none_or(|(_, b)| objective.better(cost, b)) {
+        end = Some((last, cost));
+      }
+    }
+  }
+  let (mut node, cost) = end?;
+
+  let mut order = vec![];
+  let mut mask = full;
+  loop {
+    order.push(node);
+    let previous = parent[at(mask, node)] as usize;
+    mask ^= 1 << node;
+    if mask == 0 {
+      break;
+    }
+    node = previous;
+  }
+  order.reverse();
+  Some(Path { order, cost })
+}
+
+/// Up to this many guests every seating is tried, past it [`hamiltonian`] takes over.
+const BRUTE_FORCE_GUESTS: usize = 8;
+
+/// Guests around a round table, where every pair of neighbours scores what both feel about
+/// sitting next to each other.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Table {
+  guests: Vec<String>,
+  /// Symmetric: `pairs[a][b]` is how `a` feels about `b` plus how `b` feels about `a`.
+  pairs: Vec<Vec<i64>>,
+}
+
+/// Guests in seating order, the last one next to the first, and the total score.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Seating {
+  pub order: Vec<String>,
+  pub score: i64,
+}
+
+impl Table {
+  pub fn new() -> Table {
+    Table::default()
+  }
+
+  pub fn guests(&self) -> &[String] {
+    &self.guests
+  }
+
+  /// The index of `name`, seating them if they are new.
+  fn guest(&mut self, name: &str) -> usize {
+    if let Some(i) = self.guests.iter().position(|g| g == name) {
+      return i;
+    }
+    self.guests.push(name.to_string());
+    for row in self.pairs.iter_mut() {
+      row.push(0);
+    }
+    self.pairs.push(vec![0; self.guests.len()]);
+    self.guests.len() - 1
+  }
+
+  /// Records how `a` feels about sitting next to `b`.
+  pub fn add(&mut self, a: &str, b: &str, score: i64) {
+    let (a, b) = (self.guest(a), self.guest(b));
+    self.pairs[a][b] += score;
+    self.pairs[b][a] += score;
+  }
+
+  /// Adds a guest who feels nothing about anyone and nobody feels anything about.
+  pub fn add_neutral_guest(&mut self, name: &str) {
+    self.guest(name);
+  }
+
+  fn score(&self, order: &[usize]) -> i64 {
+    if order.len() < 2 {
+      return 0;
+    }
+    let next = order.iter().cycle().skip(1);
+    order
+      .iter()
+      .zip(next)
+      .map(|(&a, &b)| self.pairs[a][b])
+      .sum()
+  }
+
+  /// Tries every seating with guest 0 in the first seat, skipping mirror images.
+  fn brute_force(&self, objective: Objective) -> Option<Vec<usize>> {
+    let n = self.guests.len();
+    if n == 0 {
+      return None;
+    }
+    (1..n)
+      .permutations(n - 1)
+      .filter(|rest| rest.first() <= rest.last())
+      .map(|rest| std::iter::once(0).chain(rest).collect::<Vec<usize>>())
+      .fold(None, |best: Option<(Vec<usize>, i64)>, order| {
+        let score = self.score(&order);
+        match best {
+          Some((_, b)) if !objective.better(score, b) => best,
+          _ => Some((order, score)),
+        }
+      })
+      .map(|(order, _)| order)
+  }
+
+  fn held_karp(&self, objective: Objective) -> Option<Vec<usize>> {
+    let n = self.guests.len();
+    let weights: Vec<Vec<Option<i64>>> = (0..n)
+      .map(|a| {
+        (0..n)
+          .map(|b| Some(self.pairs[a][b]).filter(|_| a != b))
+          .collect()
+      })
+      .collect();
+    hamiltonian(&weights, Tour::Closed, objective).map(|path| path.order)
+  }
+
+  /// The best seating, or `None` for an empty table. Panics past [`HAMILTONIAN_MAX_NODES`]
+  /// guests.
+  pub fn best(&self, objective: Objective) -> Option<Seating> {
+    let order = if self.guests.len() <= BRUTE_FORCE_GUESTS {
+      self.brute_force(objective)
+    } else {
+      self.held_karp(objective)
+    }?;
+    Some(Seating {
+      score: self.score(&order),
+      order: order.into_iter().map(|i| self.guests[i].clone()).collect(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn symmetric(n: usize, edges: &[(usize, usize, i64)]) -> Vec<Vec<Option<i64>>> {
+    let mut weights = vec![vec![None; n]; n];
+    for &(a, b, w) in edges {
+      weights[a][b] = Some(w);
+      weights[b][a] = Some(w);
+    }
+    weights
+  }
+
+  #[test]
+  fn test_open() {
+    let weights = symmetric(3, &[(0, 1, 464), (0, 2, 518), (1, 2, 141)]);
+    let shortest = hamiltonian(&weights, Tour::Open, Objective::Min).unwrap();
+    assert_eq!(shortest.cost, 605);
+    assert!(shortest.order == vec![0, 1, 2] || shortest.order == vec![2, 1, 0]);
+    let longest = hamiltonian(&weights, Tour::Open, Objective::Max).unwrap();
+    assert_eq!(longest.cost, 982);
+    assert!(longest.order == vec![1, 0, 2] || longest.order == vec![2, 0, 1]);
+  }
+
+  #[test]
+  fn test_closed() {
+    // A square with expensive diagonals, so the best round trip goes round the edge.
+    let weights = symmetric(
+      4,
+      &[
+        (0, 1, 1),
+        (1, 2, 1),
+        (2, 3, 1),
+        (3, 0, 1),
+        (0, 2, 10),
+        (1, 3, 10),
+      ],
+    );
+    let round = hamiltonian(&weights, Tour::Closed, Objective::Min).unwrap();
+    assert_eq!(round.cost, 4);
+    assert_eq!(round.order[0], 0);
+    assert_eq!(
+      hamiltonian(&weights, Tour::Closed, Objective::Max)
+        .unwrap()
+        .cost,
+      22
+    );
+    let mut one_way = weights.clone();
+    one_way[1][0] = Some(-5);
+    assert_eq!(
+      hamiltonian(&one_way, Tour::Closed, Objective::Min)
+        .unwrap()
+        .order,
+      vec![0, 3, 2, 1]
+    );
+  }
+
+  #[test]
+  fn test_table() {
+    let mut table = Table::new();
+    table.add("Alice", "Bob", 54);
+    table.add("Bob", "Alice", 83);
+    table.add("Alice", "Carol", -79);
+    table.add("Carol", "Alice", -62);
+    table.add("Bob", "Carol", -7);
+    table.add("Carol", "Bob", 60);
+    let seating = table.best(Objective::Max).unwrap();
+    assert_eq!(seating.score, 54 + 83 - 79 - 62 - 7 + 60);
+    assert_eq!(seating.order[0], "Alice");
+    table.add_neutral_guest("me");
+    let seating = table.best(Objective::Max).unwrap();
+    assert_eq!(seating.score, 54 + 83 - 7 + 60);
+    assert_eq!(seating.order.len(), 4);
+    assert_eq!(Table::new().best(Objective::Max), None);
+  }
+
+  #[test]
+  fn test_table_strategies_agree() {
+    let mut table = Table::new();
+    for a in 0..8i64 {
+      for b in 0..8i64 {
+        if a != b {
+          table.add(&a.to_string(), &b.to_string(), (a * 7 + b * 13) % 17 - 8);
+        }
+      }
+    }
+    for objective in [Objective::Min, Objective::Max].iter() {
+      let brute_force = table.brute_force(*objective).unwrap();
+      let held_karp = table.held_karp(*objective).unwrap();
+      assert_eq!(table.score(&brute_force), table.score(&held_karp));
+    }
+  }
+
+  const BAGS: &[(&str, &str, u64)] = &[
+    ("light red", "bright white", 1),
+    ("light red", "muted yellow", 2),
+    ("bright white", "shiny gold", 1),
+    ("muted yellow", "shiny gold", 2),
+    ("muted yellow", "faded blue", 9),
+    ("shiny gold", "dark olive", 1),
+    ("shiny gold", "vibrant plum", 2),
+    ("dark olive", "faded blue", 3),
+    ("dark olive", "dotted black", 4),
+    ("vibrant plum", "faded blue", 5),
+    ("vibrant plum", "dotted black", 6),
+  ];
+
+  #[test]
+  fn test_digraph_reachability() {
+    let graph = Digraph::from_edges(BAGS.iter().copied());
+    assert_eq!(graph.len(), 8);
+    let bfs = graph.bfs("shiny gold");
+    assert_eq!(bfs[0], "shiny gold");
+    assert_eq!(bfs.len(), 5);
+    assert!(bfs[1..3].contains(&"dark olive") && bfs[1..3].contains(&"vibrant plum"));
+    let dfs = graph.dfs("light red");
+    assert_eq!(dfs.len(), 8);
+    assert_eq!(dfs[0], "light red");
+    let mut ancestors: Vec<&str> = graph.ancestors("shiny gold").into_iter().collect();
+    ancestors.sort_unstable();
+    assert_eq!(
+      ancestors,
+      vec!["bright white", "light red", "muted yellow", "shiny gold"]
+    );
+    assert_eq!(graph.successors("bright white"), vec![("shiny gold", &1)]);
+    assert_eq!(graph.predecessors("light red"), vec![]);
+    assert!(graph.bfs("black").is_empty());
+  }
+
+  #[test]
+  fn test_digraph_order_and_fold() {
+    let graph = Digraph::from_edges(BAGS.iter().copied());
+    let order = graph.topological_order().unwrap();
+    let position = |name: &str| order.iter().position(|n| *n == name).unwrap();
+    for (from, to, _) in BAGS {
+      assert!(position(from) < position(to), "{} before {}", from, to);
+    }
+    let inside = graph
+      .fold_dag(|_, children| {
+        children
+          .iter()
+          .map(|(_, &count, inside)| count * (1 + **inside))
+          .sum::<u64>()
+      })
+      .unwrap();
+    assert_eq!(inside["shiny gold"], 32);
+    assert_eq!(inside["faded blue"], 0);
+
+    let mut cyclic = Digraph::from_edges(vec![("a", "b", ()), ("b", "a", ()), ("c", "c", ())]);
+    cyclic.add_edge("a", "d", ());
+    assert_eq!(
+      cyclic.topological_order(),
+      Err(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+    );
+    assert!(cyclic.fold_dag(|_, _| 0).is_err());
+  }
+
+  #[test]
+  fn test_digraph_shortest_path() {
+    let graph = Digraph::from_edges(vec![
+      ("a", "b", 1),
+      ("b", "c", 1),
+      ("a", "c", 5),
+      ("c", "d", 1),
+    ]);
+    assert_eq!(
+      graph.dijkstra("a", "d", |&w| w),
+      Some((3, vec!["a", "b", "c", "d"]))
+    );
+    assert_eq!(
+      graph.astar("a", "c", |&w| w, |n| if n == "c" { 0 } else { 1 }),
+      Some((2, vec!["a", "b", "c"]))
+    );
+    assert_eq!(graph.dijkstra("d", "a", |&w| w), None);
+    let (names, weights) = graph.weight_matrix(|&w| w as i64);
+    assert_eq!(names, vec!["a", "b", "c", "d"]);
+    assert_eq!(weights[0][2], Some(5));
+    assert_eq!(weights[2][0], None);
+  }
+
+  #[test]
+  #[should_panic(expected = "too many for Held-Karp")]
+  fn test_too_many_nodes() {
+    let n = HAMILTONIAN_MAX_NODES + 1;
+    hamiltonian(&vec![vec![Some(1); n]; n], Tour::Open, Objective::Min);
+  }
+
+  #[test]
+  fn test_missing_edges() {
+    let weights = symmetric(3, &[(0, 1, 1)]);
+    assert_eq!(hamiltonian(&weights, Tour::Open, Objective::Min), None);
+    assert_eq!(hamiltonian(&[], Tour::Open, Objective::Min), None);
+    assert_eq!(
+      hamiltonian(&[vec![None]], Tour::Closed, Objective::Min),
+      Some(Path {
+        order: vec![0],
+        cost: 0
+      })
+    );
+  }
+}
@@ -0,0 +1,386 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A cell position, `(x, y)` with `y` growing downwards like the puzzle text.
+pub type Pos = (usize, usize);
+/// A step between cells, `(dx, dy)`.
+pub type Dir = (isize, isize);
+
+/// North, east, south and west.
+pub const DIRECTIONS4: [Dir; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// The four orthogonal directions plus the diagonals, clockwise from north west.
+pub const DIRECTIONS8: [Dir; 8] = [
+  (-1, -1),
+  (0, -1),
+  (1, -1),
+  (1, 0),
+  (1, 1),
+  (0, 1),
+  (-1, 1),
+  (-1, 0),
+];
+
+/// A dense rectangular grid stored row by row.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+  pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+    Grid {
+      width,
+      height,
+      cells: vec![fill; width * height],
+    }
+  }
+}
+
+impl<T> Grid<T> {
+  /// Builds a grid from `cells` in row order. Panics unless they fill whole rows; no cells at
+  /// all make an empty grid.
+  pub fn from_vec(width: usize, cells: Vec<T>) -> Grid<T> {
+    assert!(
+      cells.is_empty() || width > 0 && cells.len().is_multiple_of(width),
+      "{} cells do not fill rows of {}",
+      cells.len(),
+      width
+    );
+    Grid {
+      width,
+      height: cells.len().checked_div(width).unwrap_or(0),
+      cells,
+    }
+  }
+
+  /// Parses one row per non-empty line, mapping each character with `cell`. Input without
+  /// any rows gives an empty grid. Panics on ragged rows.
+  pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Grid<T> {
+    let mut width = 0;
+    let mut cells = vec![];
+    for line in input.lines().map(str::trim_end).filter(|l| !l.is_empty()) {
+      let before = cells.len();
+      cells.extend(line.chars().map(&mut cell));
+      let row = cells.len() - before;
+      assert!(width == 0 || row == width, "ragged row {:?}", line);
+      width = row;
+    }
+    Grid::from_vec(width, cells)
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  pub fn contains(&self, (x, y): Pos) -> bool {
+    x < self.width && y < self.height
+  }
+
+  pub fn get(&self, pos: Pos) -> Option<&T> {
+    if self.contains(pos) {
+      Some(&self.cells[pos.1 * self.width + pos.0])
+    } else {
+      None
+    }
+  }
+
+  pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+    if self.contains(pos) {
+      Some(&mut self.cells[pos.1 * self.width + pos.0])
+    } else {
+      None
+    }
+  }
+
+  /// Treats the grid as tiling the plane in both directions. Panics on an empty grid, which
+  /// has nothing to tile with.
+  pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+    assert!(
+      self.width > 0 && self.height > 0,
+      "cannot wrap around an empty grid"
+    );
+    let x = x.rem_euclid(self.width as isize) as usize;
+    let y = y.rem_euclid(self.height as isize) as usize;
+    &self[(x, y)]
+  }
+
+  /// The cell one `dir` step away from `pos`, if it is on the grid.
+  pub fn offset(&self, (x, y): Pos, (dx, dy): Dir) -> Option<Pos> {
+    let x = x.checked_add_signed(dx)?;
+    let y = y.checked_add_signed(dy)?;
+    Some((x, y)).filter(|&p| self.contains(p))
+  }
+
+  pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    DIRECTIONS4
+      .iter()
+      .filter_map(move |&dir| self.offset(pos, dir))
+  }
+
+  pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    DIRECTIONS8
+      .iter()
+      .filter_map(move |&dir| self.offset(pos, dir))
+  }
+
+  /// Every position from `pos` in direction `dir` up to the edge, excluding `pos` itself.
+  pub fn ray(&self, pos: Pos, dir: Dir) -> impl Iterator<Item = Pos> + '_ {
+    let mut current = pos;
+    std::iter::from_fn(move || {
+      current = self.offset(current, dir)?;
+      Some(current)
+    })
+  }
+
+  /// The cells of row `y`, left to right. Panics when `y` is off the grid.
+  pub fn row(&self, y: usize) -> &[T] {
+    assert!(y < self.height, "row {} outside height {}", y, self.height);
+    &self.cells[y * self.width..(y + 1) * self.width]
+  }
+
+  pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+    self.cells.chunks(self.width.max(1))
+  }
+
+  /// The cells of column `x`, top to bottom. Panics when `x` is off the grid.
+  pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+    assert!(x < self.width, "column {} outside width {}", x, self.width);
+    self.cells[x..].iter().step_by(self.width)
+  }
+
+  pub fn positions(&self) -> impl Iterator<Item = Pos> {
+    let width = self.width;
+    (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &T> {
+    self.cells.iter()
+  }
+
+  pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+    self.cells.iter_mut()
+  }
+
+  /// A grid of the same shape, built from each position and its cell.
+  pub fn map<U>(&self, mut f: impl FnMut(Pos, &T) -> U) -> Grid<U> {
+    let cells = self
+      .positions()
+      .zip(self.cells.iter())
+      .map(|(pos, cell)| f(pos, cell))
+      .collect();
+    Grid {
+      width: self.width,
+      height: self.height,
+      cells,
+    }
+  }
+
+  fn remap(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Grid<T>
+  where
+    T: Clone,
+  {
+    let cells = (0..height)
+      .flat_map(|y| (0..width).map(move |x| (x, y)))
+      .map(|pos| self[source(pos)].clone())
+      .collect();
+    Grid {
+      width,
+      height,
+      cells,
+    }
+  }
+
+  /// Swaps rows and columns.
+  pub fn transpose(&self) -> Grid<T>
+  where
+    T: Clone,
+  {
+    self.remap(self.height, self.width, |(x, y)| (y, x))
+  }
+
+  /// Rotates a quarter turn clockwise.
+  pub fn rotate_right(&self) -> Grid<T>
+  where
+    T: Clone,
+  {
+    let h = self.height;
+    self.remap(self.height, self.width, |(x, y)| (y, h - 1 - x))
+  }
+
+  /// Rotates a quarter turn anticlockwise.
+  pub fn rotate_left(&self) -> Grid<T>
+  where
+    T: Clone,
+  {
+    let w = self.width;
+    self.remap(self.height, self.width, |(x, y)| (w - 1 - y, x))
+  }
+
+  /// Mirrors left to right.
+  pub fn flip_horizontal(&self) -> Grid<T>
+  where
+    T: Clone,
+  {
+    let w = self.width;
+    self.remap(self.width, self.height, |(x, y)| (w - 1 - x, y))
+  }
+
+  /// Mirrors top to bottom.
+  pub fn flip_vertical(&self) -> Grid<T>
+  where
+    T: Clone,
+  {
+    let h = self.height;
+    self.remap(self.width, self.height, |(x, y)| (x, h - 1 - y))
+  }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+  type Output = T;
+
+  fn index(&self, pos: Pos) -> &T {
+    self
+      .get(pos)
+      .unwrap_or_else(|| panic!("{:?} is outside {}x{}", pos, self.width, self.height))
+  }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+  fn index_mut(&mut self, pos: Pos) -> &mut T {
+    let (width, height) = (self.width, self.height);
+    self
+      .get_mut(pos)
+      .unwrap_or_else(|| panic!("{:?} is outside {}x{}", pos, width, height))
+  }
+}
+
+impl<T: Display> Display for Grid<T> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    for (y, row) in self.rows().enumerate() {
+      if y > 0 {
+        writeln!(f)?;
+      }
+      for cell in row {
+        write!(f, "{}", cell)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TEST_INPUT: &str = "abc
+def";
+
+  #[test]
+  fn test_parse() {
+    let grid = Grid::parse(TEST_INPUT, |c| c);
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 0)], 'b');
+    assert_eq!(grid[(2, 1)], 'f');
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.row(1), &['d', 'e', 'f']);
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(grid.to_string(), TEST_INPUT);
+    assert_eq!(
+      Grid::parse("#.\n.#\n", |c| c == '#')
+        .iter()
+        .filter(|c| **c)
+        .count(),
+      2
+    );
+  }
+
+  #[test]
+  fn test_parse_empty() {
+    let grid = Grid::parse("", |c| c);
+    assert_eq!((grid.width(), grid.height()), (0, 0));
+    assert_eq!(grid.get((0, 0)), None);
+    assert_eq!(grid.rows().count(), 0);
+    assert_eq!(grid.positions().count(), 0);
+    assert_eq!(Grid::parse("\n\n", |c| c), grid);
+  }
+
+  #[test]
+  #[should_panic(expected = "column 3 outside width 3")]
+  fn test_column_out_of_bounds() {
+    Grid::parse(TEST_INPUT, |c| c).column(3).count();
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_parse_ragged() {
+    Grid::parse("ab\nc", |c| c);
+  }
+
+  #[test]
+  fn test_get_wrapping() {
+    let grid = Grid::parse(TEST_INPUT, |c| c);
+    assert_eq!(*grid.get_wrapping(4, 0), 'b');
+    assert_eq!(*grid.get_wrapping(-1, -1), 'f');
+    assert_eq!(*grid.get_wrapping(3, 3), 'd');
+  }
+
+  #[test]
+  #[should_panic(expected = "cannot wrap around an empty grid")]
+  fn test_get_wrapping_empty() {
+    Grid::parse("", |c| c).get_wrapping(0, 0);
+  }
+
+  #[test]
+  fn test_neighbours() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    assert_eq!(
+      grid.neighbours4((0, 0)).collect::<Vec<Pos>>(),
+      vec![(1, 0), (0, 1)]
+    );
+    assert_eq!(
+      grid.neighbours8((0, 0)).collect::<Vec<Pos>>(),
+      vec![(1, 0), (1, 1), (0, 1)]
+    );
+  }
+
+  #[test]
+  fn test_ray() {
+    let grid = Grid::new(4, 3, 0);
+    assert_eq!(
+      grid.ray((0, 0), (1, 1)).collect::<Vec<Pos>>(),
+      vec![(1, 1), (2, 2)]
+    );
+    assert_eq!(
+      grid.ray((1, 2), (0, -1)).collect::<Vec<Pos>>(),
+      vec![(1, 1), (1, 0)]
+    );
+    assert_eq!(grid.ray((3, 0), (1, 0)).count(), 0);
+  }
+
+  #[test]
+  fn test_rotate_and_flip() {
+    let grid = Grid::parse(TEST_INPUT, |c| c);
+    assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.rotate_right().rotate_left(), grid);
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+  }
+
+  #[test]
+  fn test_map() {
+    let grid = Grid::parse(TEST_INPUT, |c| c);
+    let mapped = grid.map(|(x, y), c| if x == y { c.to_ascii_uppercase() } else { *c });
+    assert_eq!(mapped.to_string(), "Abc\ndEf");
+  }
+}
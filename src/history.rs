@@ -0,0 +1,332 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Value};
+
+use crate::solution::{BenchReport, PuzzleId};
+use crate::timing::BenchmarkResult;
+
+/// Relative slowdowns below this are ignored even when statistically significant.
+const MIN_SLOWDOWN: f64 = 0.05;
+/// Welch's t above this is treated as a real difference, roughly 95% one sided.
+const T_CRITICAL: f64 = 2.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+  pub id: PuzzleId,
+  /// `parse`, `1` or `2`.
+  pub stage: String,
+  pub commit: String,
+  pub machine: String,
+  pub timestamp: u64,
+  pub samples: usize,
+  pub mean_ns: f64,
+  pub median_ns: f64,
+  pub stddev_ns: f64,
+}
+
+impl Entry {
+  pub fn new(
+    id: PuzzleId,
+    stage: &str,
+    result: &BenchmarkResult,
+    commit: &str,
+    machine: &str,
+  ) -> Entry {
+    Entry {
+      id,
+      stage: stage.to_string(),
+      commit: commit.to_string(),
+      machine: machine.to_string(),
+      timestamp: SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs()),
+      samples: result.samples,
+      mean_ns: result.mean.as_nanos() as f64,
+      median_ns: result.median.as_nanos() as f64,
+      stddev_ns: result.stddev.as_nanos() as f64,
+    }
+  }
+
+  pub fn from_report(report: &BenchReport, commit: &str, machine: &str) -> Vec<Entry> {
+    let stages = [
+      ("parse", Some(&report.parse)),
+      ("1", report.part_one.as_ref()),
+      ("2", report.part_two.as_ref()),
+    ];
+    stages
+      .iter()
+      .filter_map(|(stage, result)| {
+        result.map(|r| Entry::new(report.id, stage, r, commit, machine))
+      })
+      .collect()
+  }
+
+  fn to_json(&self) -> Value {
+    json!({
+      "puzzle": self.id.to_string(),
+      "stage": self.stage,
+      "commit": self.commit,
+      "machine": self.machine,
+      "timestamp": self.timestamp,
+      "samples": self.samples,
+      "mean_ns": self.mean_ns,
+      "median_ns": self.median_ns,
+      "stddev_ns": self.stddev_ns,
+    })
+  }
+
+  fn from_json(value: &Value) -> Option<Entry> {
+    let mut id = value["puzzle"].as_str()?.split('-');
+    let id = PuzzleId::new(id.next()?.parse().ok()?, id.next()?.parse().ok()?);
+    Some(Entry {
+      id,
+      stage: value["stage"].as_str()?.to_string(),
+      commit: value["commit"].as_str()?.to_string(),
+      machine: value["machine"].as_str()?.to_string(),
+      timestamp: value["timestamp"].as_u64()?,
+      samples: value["samples"].as_u64()? as usize,
+      mean_ns: value["mean_ns"].as_f64()?,
+      median_ns: value["median_ns"].as_f64()?,
+      stddev_ns: value["stddev_ns"].as_f64()?,
+    })
+  }
+
+  fn same_series(&self, other: &Entry) -> bool {
+    self.id == other.id && self.stage == other.stage && self.machine == other.machine
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+  pub baseline: Entry,
+  /// Relative change of the mean, positive when slower.
+  pub change: f64,
+  pub t: f64,
+}
+
+impl Comparison {
+  pub fn is_slowdown(&self) -> bool {
+    self.change > MIN_SLOWDOWN && self.t > T_CRITICAL
+  }
+}
+
+/// Welch's t-test on the two means, using each run's sample count and standard deviation.
+pub fn compare(baseline: &Entry, current: &Entry) -> Comparison {
+  let variance = |e: &Entry| e.stddev_ns.powi(2) / e.samples.max(1) as f64;
+  let error = (variance(baseline) + variance(current)).sqrt();
+  let diff = current.mean_ns - baseline.mean_ns;
+  let t = if error > 0.0 {
+    diff / error
+  } else if diff > 0.0 {
+    f64::INFINITY
+  } else {
+    0.0
+  };
+  Comparison {
+    baseline: baseline.clone(),
+    change: diff / baseline.mean_ns.max(1.0),
+    t,
+  }
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+  Invalid(String),
+  Io(io::Error),
+}
+
+impl Display for HistoryError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      HistoryError::Invalid(e) => write!(f, "invalid benchmark history: {}", e),
+      HistoryError::Io(e) => write!(f, "failed to read or write benchmark history: {}", e),
+    }
+  }
+}
+
+impl std::error::Error for HistoryError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      HistoryError::Io(e) => Some(e),
+      _ => None,
+    }
+  }
+}
+
+impl From<io::Error> for HistoryError {
+  fn from(e: io::Error) -> Self {
+    HistoryError::Io(e)
+  }
+}
+
+/// What `bench --compare` measures a run against.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Baseline {
+  /// The previous run, which catches sudden slowdowns but lets slow creep through.
+  Previous,
+  /// The latest run at a commit whose hash starts with this, which stays put across runs.
+  Commit(String),
+}
+
+/// Benchmark runs stored as a JSON array, oldest first.
+#[derive(Debug)]
+pub struct History {
+  path: PathBuf,
+  entries: Vec<Entry>,
+}
+
+impl History {
+  pub fn load(path: &Path) -> Result<History, HistoryError> {
+    let entries = match fs::read_to_string(path) {
+      Ok(contents) => {
+        let invalid = || HistoryError::Invalid(path.display().to_string());
+        let values: Vec<Value> = serde_json::from_str(&contents).map_err(|_| invalid())?;
+        values
+          .iter()
+          .map(|v| Entry::from_json(v).ok_or_else(invalid))
+          .collect::<Result<Vec<Entry>, HistoryError>>()?
+      }
+      Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+      Err(e) => return Err(e.into()),
+    };
+    Ok(History {
+      path: path.to_path_buf(),
+      entries,
+    })
+  }
+
+  pub fn entries(&self) -> &[Entry] {
+    &self.entries
+  }
+
+  /// The most recent entry for the same puzzle, stage and machine that `baseline` picks out.
+  pub fn baseline(&self, entry: &Entry, baseline: &Baseline) -> Option<&Entry> {
+    self
+      .entries
+      .iter()
+      .rev()
+      .filter(|e| e.same_series(entry))
+      .find(|e| match baseline {
+        Baseline::Previous => true,
+        Baseline::Commit(commit) => e.commit.starts_with(commit.as_str()),
+      })
+  }
+
+  pub fn push(&mut self, entry: Entry) {
+    self.entries.push(entry);
+  }
+
+  pub fn save(&self) -> Result<(), HistoryError> {
+    let values: Vec<Value> = self.entries.iter().map(Entry::to_json).collect();
+    let contents = serde_json::to_string_pretty(&values).expect("history serializes");
+    if let Some(dir) = self.path.parent() {
+      fs::create_dir_all(dir)?;
+    }
+    fs::write(&self.path, contents + "\n")?;
+    Ok(())
+  }
+}
+
+/// Short hash of `HEAD`, suffixed with `-dirty` when the work tree has changes.
+pub fn current_commit() -> String {
+  let git = |args: &[&str]| {
+    Command::new("git")
+      .args(args)
+      .current_dir(crate::config::crate_root())
+      .output()
+      .ok()
+      .filter(|o| o.status.success())
+      .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+  };
+  match git(&["rev-parse", "--short", "HEAD"]) {
+    Some(commit) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+      Some(status) if !status.is_empty() => format!("{}-dirty", commit),
+      _ => commit,
+    },
+    None => "unknown".to_string(),
+  }
+}
+
+pub fn machine() -> String {
+  std::env::var("HOSTNAME")
+    .ok()
+    .or_else(|| fs::read_to_string("/etc/hostname").ok())
+    .or_else(|| std::env::var("COMPUTERNAME").ok())
+    .map(|h| h.trim().to_string())
+    .filter(|h| !h.is_empty())
+    .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn entry(mean_ns: f64, stddev_ns: f64, samples: usize) -> Entry {
+    Entry {
+      id: PuzzleId::new(2020, 15),
+      stage: "2".to_string(),
+      commit: "abc123".to_string(),
+      machine: "box".to_string(),
+      timestamp: 0,
+      samples,
+      mean_ns,
+      median_ns: mean_ns,
+      stddev_ns,
+    }
+  }
+
+  #[test]
+  fn test_compare() {
+    let baseline = entry(1000.0, 10.0, 50);
+    assert!(compare(&baseline, &entry(1200.0, 10.0, 50)).is_slowdown());
+    assert!(!compare(&baseline, &entry(1000.0, 10.0, 50)).is_slowdown());
+    assert!(!compare(&baseline, &entry(800.0, 10.0, 50)).is_slowdown());
+    // big change, but the noise swamps it
+    assert!(!compare(&baseline, &entry(1200.0, 2000.0, 5)).is_slowdown());
+    // significant, but too small to care about
+    assert!(!compare(&baseline, &entry(1020.0, 1.0, 100)).is_slowdown());
+  }
+
+  #[test]
+  fn test_history_round_trip() {
+    let path = std::env::temp_dir().join(format!(
+      "advent-of-rust-history-{}.json",
+      std::process::id()
+    ));
+    let _ = fs::remove_file(&path);
+    let mut history = History::load(&path).unwrap();
+    assert!(history.entries().is_empty());
+
+    let first = entry(1000.0, 10.0, 50);
+    let other_machine = Entry {
+      machine: "other".to_string(),
+      ..entry(5.0, 1.0, 50)
+    };
+    let second = Entry {
+      commit: "def456".to_string(),
+      ..entry(1100.0, 10.0, 50)
+    };
+    history.push(first.clone());
+    history.push(second.clone());
+    history.push(other_machine);
+    history.save().unwrap();
+
+    let history = History::load(&path).unwrap();
+    assert_eq!(history.entries().len(), 3);
+    let current = entry(0.0, 0.0, 1);
+    assert_eq!(
+      history.baseline(&current, &Baseline::Previous),
+      Some(&second)
+    );
+    let pinned = Baseline::Commit("abc".to_string());
+    assert_eq!(history.baseline(&current, &pinned), Some(&first));
+    let unknown = Baseline::Commit("fff".to_string());
+    assert_eq!(history.baseline(&current, &unknown), None);
+  }
+}
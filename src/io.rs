@@ -1,59 +1,624 @@
+use lazy_static::lazy_static;
+use regex::Regex;
 use reqwest::blocking::Client;
-use reqwest::Error;
+use reqwest::StatusCode;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
 use std::fs::File;
 use std::io;
-use std::io::Read;
-use std::path::Path;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
 
-pub fn download_input(url: &str) -> Result<String, Error> {
+use crate::config::Config;
+use crate::ledger::{Ledger, LedgerError};
+use crate::solution::{Part, PuzzleId};
+
+#[derive(Debug)]
+pub enum InputError {
+  EmptyName,
+  InvalidName(String),
+  MissingSession,
+  InvalidConfig(String),
+  Offline(PathBuf),
+  NotUnlocked {
+    year: u16,
+    day: u8,
+  },
+  /// The server rejected the session cookie, usually because it expired.
+  Unauthorized,
+  Status(u16),
+  /// A successful response whose body is an error page rather than puzzle input.
+  InvalidBody(String),
+  Download(reqwest::Error),
+  Io(io::Error),
+}
+
+impl Display for InputError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      InputError::EmptyName => write!(f, "empty input name"),
+      InputError::InvalidName(name) => {
+        write!(f, "invalid input name '{}', expected YYYY-DD", name)
+      }
+      InputError::MissingSession => write!(f, "no session cookie configured"),
+      InputError::InvalidConfig(e) => write!(f, "invalid config file: {}", e),
+      InputError::Offline(path) => write!(f, "offline and {} is not cached", path.display()),
+      InputError::NotUnlocked { year, day } => {
+        write!(f, "puzzle {}-{:02} not yet unlocked", year, day)
+      }
+      InputError::Unauthorized => write!(f, "session cookie rejected, log in again"),
+      InputError::Status(status) => write!(f, "unexpected response status {}", status),
+      InputError::InvalidBody(body) => write!(f, "response is not puzzle input: {}", body),
+      InputError::Download(e) => write!(f, "failed to download input: {}", e),
+      InputError::Io(e) => write!(f, "failed to read or write input or config: {}", e),
+    }
+  }
+}
+
+impl std::error::Error for InputError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      InputError::Download(e) => Some(e),
+      InputError::Io(e) => Some(e),
+      _ => None,
+    }
+  }
+}
+
+impl From<reqwest::Error> for InputError {
+  fn from(e: reqwest::Error) -> Self {
+    InputError::Download(e)
+  }
+}
+
+impl From<io::Error> for InputError {
+  fn from(e: io::Error) -> Self {
+    InputError::Io(e)
+  }
+}
+
+/// Why an answer was not submitted or its verdict not recorded.
+#[derive(Debug)]
+pub enum SubmitError {
+  /// The ledger could not be read or written, or already rules the answer out.
+  Ledger(LedgerError),
+  Offline,
+  /// Talking to the server failed.
+  Request(InputError),
+}
+
+impl Display for SubmitError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      SubmitError::Ledger(e) => write!(f, "{}", e),
+      SubmitError::Offline => write!(f, "not submitting in offline mode"),
+      SubmitError::Request(e) => write!(f, "failed to submit answer: {}", e),
+    }
+  }
+}
+
+impl std::error::Error for SubmitError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      SubmitError::Ledger(e) => Some(e),
+      SubmitError::Offline => None,
+      SubmitError::Request(e) => Some(e),
+    }
+  }
+}
+
+impl From<LedgerError> for SubmitError {
+  fn from(e: LedgerError) -> Self {
+    SubmitError::Ledger(e)
+  }
+}
+
+impl From<InputError> for SubmitError {
+  fn from(e: InputError) -> Self {
+    SubmitError::Request(e)
+  }
+}
+
+impl From<reqwest::Error> for SubmitError {
+  fn from(e: reqwest::Error) -> Self {
+    SubmitError::Request(InputError::Download(e))
+  }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Verdict {
+  Correct,
+  Wrong,
+  TooHigh,
+  TooLow,
+  Wait(Duration),
+  /// Submitted for a part that is locked or already solved.
+  WrongLevel,
+}
+
+impl Verdict {
+  pub fn is_wrong(&self) -> bool {
+    matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+  }
+}
+
+impl Display for Verdict {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Verdict::Correct => write!(f, "correct"),
+      Verdict::Wrong => write!(f, "wrong"),
+      Verdict::TooHigh => write!(f, "too high"),
+      Verdict::TooLow => write!(f, "too low"),
+      Verdict::Wait(duration) => write!(f, "wait {}s", duration.as_secs()),
+      Verdict::WrongLevel => write!(f, "wrong level"),
+    }
+  }
+}
+
+impl FromStr for Verdict {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "correct" => Ok(Verdict::Correct),
+      "wrong" => Ok(Verdict::Wrong),
+      "too high" => Ok(Verdict::TooHigh),
+      "too low" => Ok(Verdict::TooLow),
+      "wrong level" => Ok(Verdict::WrongLevel),
+      _ => s
+        .strip_prefix("wait ")
+        .and_then(|secs| secs.strip_suffix('s'))
+        .and_then(|secs| secs.parse().ok())
+        .map(|secs| Verdict::Wait(Duration::from_secs(secs)))
+        .ok_or_else(|| format!("invalid verdict '{}'", s)),
+    }
+  }
+}
+
+fn parse_name(name: &str) -> Result<(u16, u8), InputError> {
+  if name.is_empty() {
+    return Err(InputError::EmptyName);
+  }
+  let invalid = || InputError::InvalidName(name.to_string());
+  let mut parts = name.split('-');
+  let year = parts
+    .next()
+    .and_then(|y| y.parse().ok())
+    .ok_or_else(invalid)?;
+  let day = parts
+    .next()
+    .and_then(|d| d.parse().ok())
+    .ok_or_else(invalid)?;
+  if parts.next().is_some() || !(1..=25).contains(&day) {
+    return Err(invalid());
+  }
+  Ok((year, day))
+}
+
+pub fn download_input(config: &Config, year: u16, day: u8) -> Result<String, InputError> {
+  let url = format!("{}/{}/day/{}/input", config.base_url, year, day);
+  let cookie = config.session.as_ref().ok_or(InputError::MissingSession)?;
   println!("downloading input from {}", url);
   let client = Client::new();
-  let cookie = read_cookie();
   let response = client
-    .get(url)
+    .get(&url)
     .header("Cookie", format!("session={}", cookie))
     .send()?;
-  let text = response.text()?;
-  Ok(text)
+  let status = response.status();
+  let body = response.text()?;
+  check_response(status, &body).map_err(|e| match e {
+    InputError::Status(404) => InputError::NotUnlocked { year, day },
+    e => e,
+  })?;
+  Ok(body)
 }
 
-fn read_cookie() -> String {
-  let mut file = File::open(".session_cookie").expect("failed to open .session_cookie file");
-  let mut contents = String::new();
-  file
-    .read_to_string(&mut contents)
-    .expect("failed to read .session_cookie file");
-  return contents;
-}
-
-pub fn read_input(filename: &str) -> String {
-  if filename.is_empty() {
-    panic!("empty filename")
-  }
-  let filepath = format!("inputs/{}", filename);
-  let path = Path::new(&filepath);
-  let file = match path.exists() {
-    true => File::open(path),
-    false => {
-      let parts: Vec<u32> = filename
-        .split('-')
-        .map(|x: &str| x.parse().unwrap())
-        .collect();
-      let url = format!(
-        "https://adventofcode.com/{}/day/{}/input",
-        parts[0], parts[1]
-      );
-      let response = download_input(&url);
-      let mut out = File::create(&path).expect("failed to create file");
-      io::copy(&mut response.unwrap().as_bytes(), &mut out).expect("failed to copy contents");
-      File::open(&path)
-    }
-  };
+fn check_response(status: StatusCode, body: &str) -> Result<(), InputError> {
+  if body.contains("Please log in") {
+    return Err(InputError::Unauthorized);
+  }
+  if !status.is_success() {
+    return Err(InputError::Status(status.as_u16()));
+  }
+  let first_line = body.lines().next().unwrap_or("").trim();
+  if first_line.is_empty()
+    || first_line.starts_with('<')
+    || first_line.starts_with("Please don't repeatedly request")
+  {
+    return Err(InputError::InvalidBody(
+      first_line.chars().take(80).collect(),
+    ));
+  }
+  Ok(())
+}
+
+fn parse_verdict(body: &str) -> Result<Verdict, InputError> {
+  lazy_static! {
+    static ref WAIT: Regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+  }
+  if body.contains("That's the right answer") {
+    return Ok(Verdict::Correct);
+  }
+  if body.contains("That's not the right answer") {
+    return Ok(if body.contains("your answer is too high") {
+      Verdict::TooHigh
+    } else if body.contains("your answer is too low") {
+      Verdict::TooLow
+    } else {
+      Verdict::Wrong
+    });
+  }
+  if body.contains("You gave an answer too recently") {
+    let secs = WAIT.captures(body).map_or(60, |c| {
+      let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+      minutes * 60 + c[2].parse::<u64>().unwrap()
+    });
+    return Ok(Verdict::Wait(Duration::from_secs(secs)));
+  }
+  if body.contains("You don't seem to be solving the right level") {
+    return Ok(Verdict::WrongLevel);
+  }
+  Err(InputError::InvalidBody(
+    body.lines().next().unwrap_or("").chars().take(80).collect(),
+  ))
+}
+
+pub fn submit_answer(year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, SubmitError> {
+  submit_answer_with(&Config::load()?, year, day, part, answer)
+}
+
+/// Posts `answer` unless the ledger already rules it out, and records the server's verdict.
+pub fn submit_answer_with(
+  config: &Config,
+  year: u16,
+  day: u8,
+  part: Part,
+  answer: &str,
+) -> Result<Verdict, SubmitError> {
+  let id = PuzzleId::new(year, day);
+  let mut ledger = Ledger::load(&config.ledger_path())?;
+  if let Some(verdict) = ledger.check(id, part, answer)? {
+    return Ok(verdict);
+  }
+  if config.offline {
+    return Err(SubmitError::Offline);
+  }
+  let cookie = config.session.as_ref().ok_or(InputError::MissingSession)?;
+  let url = format!("{}/{}/day/{}/answer", config.base_url, year, day);
+  let level = part.number().to_string();
+  let response = Client::new()
+    .post(&url)
+    .header("Cookie", format!("session={}", cookie))
+    .form(&[("level", level.as_str()), ("answer", answer)])
+    .send()?;
+  let status = response.status();
+  let body = response.text()?;
+  if body.contains("Please log in") {
+    return Err(InputError::Unauthorized.into());
+  }
+  if !status.is_success() {
+    return Err(InputError::Status(status.as_u16()).into());
+  }
+  let verdict = parse_verdict(&body)?;
+  ledger.record(id, part, answer, verdict)?;
+  Ok(verdict)
+}
+
+/// Writes next to `path` and renames into place, so readers never see a partial file.
+fn write_atomic(path: &Path, contents: &str) -> Result<(), InputError> {
+  let dir = path.parent().unwrap_or_else(|| Path::new("."));
+  fs::create_dir_all(dir)?;
+  let name = path.file_name().unwrap_or_default().to_string_lossy();
+  let temp = dir.join(format!(".{}.{}.tmp", name, std::process::id()));
+  let result = File::create(&temp)
+    .and_then(|mut out| {
+      out.write_all(contents.as_bytes())?;
+      out.sync_all()
+    })
+    .and_then(|_| fs::rename(&temp, path));
+  if result.is_err() {
+    let _ = fs::remove_file(&temp);
+  }
+  Ok(result?)
+}
+
+pub fn read_input(filename: &str) -> Result<String, InputError> {
+  read_input_with(&Config::load()?, filename)
+}
+
+pub fn read_input_with(config: &Config, filename: &str) -> Result<String, InputError> {
+  let (year, day) = parse_name(filename)?;
+  let path = config.input_dir.join(filename);
+  if !path.exists() {
+    if config.offline {
+      return Err(InputError::Offline(path));
+    }
+    let contents = download_input(config, year, day)?;
+    write_atomic(&path, &contents)?;
+    return Ok(contents);
+  }
   let mut contents = String::new();
+  File::open(&path)?.read_to_string(&mut contents)?;
+  Ok(contents)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::mock_server::MockServer;
+
+  fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("advent-of-rust-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+  }
+
+  fn answer_page(message: &str) -> String {
+    format!(
+      "<html><body><main><article><p>{}</p></article></main></body></html>",
+      message
+    )
+  }
+
+  fn config(input_dir: &Path, base_url: &str) -> Config {
+    Config {
+      input_dir: input_dir.to_path_buf(),
+      session: Some("secret".to_string()),
+      offline: false,
+      base_url: base_url.to_string(),
+      answers_dir: input_dir.join("answers"),
+      bench_history: input_dir.join("bench_history.json"),
+    }
+  }
 
-  file
-    .unwrap()
-    .read_to_string(&mut contents)
-    .expect("unable to read contents");
-  return contents;
+  #[test]
+  fn test_parse_name() {
+    assert_eq!(parse_name("2020-01").unwrap(), (2020, 1));
+    assert_eq!(parse_name("2015-25").unwrap(), (2015, 25));
+    assert!(matches!(parse_name(""), Err(InputError::EmptyName)));
+    assert!(matches!(
+      parse_name("2020"),
+      Err(InputError::InvalidName(_))
+    ));
+    assert!(matches!(
+      parse_name("2020-xx"),
+      Err(InputError::InvalidName(_))
+    ));
+    assert!(matches!(
+      parse_name("2020-26"),
+      Err(InputError::InvalidName(_))
+    ));
+    assert!(matches!(
+      parse_name("2020-01-01"),
+      Err(InputError::InvalidName(_))
+    ));
+  }
+
+  #[test]
+  fn test_read_input_invalid_name() {
+    let dir = temp_dir("invalid-name");
+    let config = Config {
+      offline: true,
+      ..config(&dir, "http://127.0.0.1:1")
+    };
+    assert!(matches!(
+      read_input_with(&config, "nonsense"),
+      Err(InputError::InvalidName(_))
+    ));
+  }
+
+  #[test]
+  fn test_read_input_missing_session() {
+    let dir = temp_dir("missing-session");
+    let config = Config {
+      session: None,
+      ..config(&dir, "http://127.0.0.1:1")
+    };
+    assert!(matches!(
+      read_input_with(&config, "2020-01"),
+      Err(InputError::MissingSession)
+    ));
+  }
+
+  #[test]
+  fn test_read_input_offline() {
+    let server = MockServer::start(vec![("GET /2020/day/1/input", 200, "1\n2\n")]);
+    let dir = temp_dir("offline");
+    let config = Config {
+      offline: true,
+      ..config(&dir, &server.url)
+    };
+    assert!(matches!(
+      read_input_with(&config, "2020-01"),
+      Err(InputError::Offline(_))
+    ));
+    assert!(server.requests().is_empty());
+
+    fs::write(dir.join("2020-01"), "cached").unwrap();
+    assert_eq!(read_input_with(&config, "2020-01").unwrap(), "cached");
+  }
+
+  #[test]
+  fn test_read_input_downloads_and_caches() {
+    let server = MockServer::start(vec![("GET /2020/day/1/input", 200, "1721\n979\n")]);
+    let dir = temp_dir("download");
+    let config = config(&dir, &server.url);
+
+    assert_eq!(read_input_with(&config, "2020-01").unwrap(), "1721\n979\n");
+    assert_eq!(
+      fs::read_to_string(dir.join("2020-01")).unwrap(),
+      "1721\n979\n"
+    );
+    assert_eq!(read_input_with(&config, "2020-01").unwrap(), "1721\n979\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2020/day/1/input");
+    assert!(requests[0].body.is_empty());
+    assert_eq!(requests[0].headers["cookie"], "session=secret");
+  }
+
+  #[test]
+  fn test_read_input_not_unlocked() {
+    let server = MockServer::start(vec![]);
+    let dir = temp_dir("not-unlocked");
+    assert!(matches!(
+      read_input_with(&config(&dir, &server.url), "2020-25"),
+      Err(InputError::NotUnlocked {
+        year: 2020,
+        day: 25
+      })
+    ));
+    assert!(!dir.join("2020-25").exists());
+  }
+
+  #[test]
+  fn test_read_input_rejects_error_pages() {
+    let server = MockServer::start(vec![
+      (
+        "GET /2020/day/1/input",
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+      ),
+      ("GET /2020/day/2/input", 500, "Internal Server Error\n"),
+      (
+        "GET /2020/day/3/input",
+        200,
+        "Please don't repeatedly request this endpoint before it unlocks!\n",
+      ),
+      (
+        "GET /2020/day/4/input",
+        200,
+        "<!DOCTYPE html>\n<html></html>\n",
+      ),
+      ("GET /2020/day/5/input", 200, ""),
+    ]);
+    let dir = temp_dir("error-pages");
+    let config = config(&dir, &server.url);
+
+    assert!(matches!(
+      read_input_with(&config, "2020-01"),
+      Err(InputError::Unauthorized)
+    ));
+    assert!(matches!(
+      read_input_with(&config, "2020-02"),
+      Err(InputError::Status(500))
+    ));
+    for name in &["2020-03", "2020-04", "2020-05"] {
+      assert!(matches!(
+        read_input_with(&config, name),
+        Err(InputError::InvalidBody(_))
+      ));
+    }
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+  }
+
+  #[test]
+  fn test_write_atomic() {
+    let dir = temp_dir("write-atomic").join("nested");
+    let path = dir.join("2020-01");
+    write_atomic(&path, "first").unwrap();
+    write_atomic(&path, "second").unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+  }
+
+  #[test]
+  fn test_parse_verdict() {
+    let verdict = |message: &str| parse_verdict(&answer_page(message)).unwrap();
+    assert_eq!(
+      verdict("That's the right answer! You are one gold star closer."),
+      Verdict::Correct
+    );
+    assert_eq!(
+      verdict("That's not the right answer; your answer is too high."),
+      Verdict::TooHigh
+    );
+    assert_eq!(
+      verdict("That's not the right answer; your answer is too low."),
+      Verdict::TooLow
+    );
+    assert_eq!(
+      verdict("That's not the right answer. If you're stuck, ..."),
+      Verdict::Wrong
+    );
+    assert_eq!(
+      verdict("You gave an answer too recently. You have 1m 5s left to wait."),
+      Verdict::Wait(Duration::from_secs(65))
+    );
+    assert_eq!(
+      verdict("You gave an answer too recently. You have 37s left to wait."),
+      Verdict::Wait(Duration::from_secs(37))
+    );
+    assert_eq!(
+      verdict("You don't seem to be solving the right level.  Did you already complete it?"),
+      Verdict::WrongLevel
+    );
+    assert!(parse_verdict("<html></html>").is_err());
+  }
+
+  #[test]
+  fn test_verdict_round_trip() {
+    for verdict in &[
+      Verdict::Correct,
+      Verdict::Wrong,
+      Verdict::TooHigh,
+      Verdict::TooLow,
+      Verdict::Wait(Duration::from_secs(90)),
+      Verdict::WrongLevel,
+    ] {
+      assert_eq!(verdict.to_string().parse::<Verdict>(), Ok(*verdict));
+    }
+  }
+
+  #[test]
+  fn test_submit_answer() {
+    let too_low = answer_page("That's not the right answer; your answer is too low.");
+    let server = MockServer::start(vec![("POST /2020/day/1/answer", 200, &too_low)]);
+    let dir = temp_dir("submit");
+    let config = config(&dir, &server.url);
+
+    assert_eq!(
+      submit_answer_with(&config, 2020, 1, Part::Two, "100").unwrap(),
+      Verdict::TooLow
+    );
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].headers["cookie"], "session=secret");
+    assert_eq!(requests[0].body, "level=2&answer=100");
+
+    assert!(matches!(
+      submit_answer_with(&config, 2020, 1, Part::Two, "100"),
+      Err(SubmitError::Ledger(LedgerError::Rejected(_)))
+    ));
+    assert!(matches!(
+      submit_answer_with(&config, 2020, 1, Part::Two, "99"),
+      Err(SubmitError::Ledger(LedgerError::Rejected(_)))
+    ));
+    assert!(matches!(
+      submit_answer_with(
+        &Config {
+          offline: true,
+          ..config.clone()
+        },
+        2020,
+        1,
+        Part::Two,
+        "101"
+      ),
+      Err(SubmitError::Offline)
+    ));
+    assert_eq!(server.requests().len(), 1);
+
+    let ledger = Ledger::load(&config.ledger_path()).unwrap();
+    assert_eq!(
+      ledger.bounds(PuzzleId::new(2020, 1), Part::Two),
+      (Some(100), None)
+    );
+  }
 }
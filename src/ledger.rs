@@ -0,0 +1,274 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::io::Verdict;
+use crate::solution::{Part, PuzzleId};
+
+#[derive(Debug)]
+pub enum LedgerError {
+  Invalid(String),
+  /// The ledger already knows how this submission would turn out.
+  Rejected(String),
+  Io(io::Error),
+}
+
+impl Display for LedgerError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      LedgerError::Invalid(e) => write!(f, "invalid answer ledger: {}", e),
+      LedgerError::Rejected(reason) => write!(f, "not submitting: {}", reason),
+      LedgerError::Io(e) => write!(f, "failed to read or write answer ledger: {}", e),
+    }
+  }
+}
+
+impl std::error::Error for LedgerError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      LedgerError::Io(e) => Some(e),
+      _ => None,
+    }
+  }
+}
+
+impl From<io::Error> for LedgerError {
+  fn from(e: io::Error) -> Self {
+    LedgerError::Io(e)
+  }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Attempt {
+  pub id: PuzzleId,
+  pub part: Part,
+  pub answer: String,
+  pub verdict: Verdict,
+  pub timestamp: u64,
+}
+
+/// Append-only record of every submitted answer, one tab separated attempt per line.
+#[derive(Debug)]
+pub struct Ledger {
+  path: PathBuf,
+  attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+  pub fn load(path: &Path) -> Result<Ledger, LedgerError> {
+    let contents = match fs::read_to_string(path) {
+      Ok(contents) => contents,
+      Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+      Err(e) => return Err(e.into()),
+    };
+    let attempts = contents
+      .lines()
+      .filter(|line| !line.trim().is_empty())
+      .enumerate()
+      .map(|(i, line)| {
+        parse_attempt(line)
+          .ok_or_else(|| LedgerError::Invalid(format!("{} line {}", path.display(), i + 1)))
+      })
+      .collect::<Result<Vec<Attempt>, LedgerError>>()?;
+    Ok(Ledger {
+      path: path.to_path_buf(),
+      attempts,
+    })
+  }
+
+  pub fn attempts(&self, id: PuzzleId, part: Part) -> impl Iterator<Item = &Attempt> {
+    self
+      .attempts
+      .iter()
+      .filter(move |a| a.id == id && a.part == part)
+  }
+
+  pub fn correct(&self, id: PuzzleId, part: Part) -> Option<&str> {
+    self
+      .attempts(id, part)
+      .find(|a| a.verdict == Verdict::Correct)
+      .map(|a| a.answer.as_str())
+  }
+
+  /// Decides from past attempts alone whether `answer` is worth submitting. `Ok(Some(_))` is
+  /// a known verdict, `Ok(None)` means the server has to be asked.
+  pub fn check(
+    &self,
+    id: PuzzleId,
+    part: Part,
+    answer: &str,
+  ) -> Result<Option<Verdict>, LedgerError> {
+    if let Some(correct) = self.correct(id, part) {
+      if correct == answer {
+        return Ok(Some(Verdict::Correct));
+      }
+      return Err(LedgerError::Rejected(format!(
+        "{} {} already solved with {}",
+        id, part, correct
+      )));
+    }
+    if self
+      .attempts(id, part)
+      .any(|a| a.answer == answer && a.verdict.is_wrong())
+    {
+      return Err(LedgerError::Rejected(format!(
+        "{} was already submitted as a wrong answer",
+        answer
+      )));
+    }
+    if let Ok(value) = answer.parse::<i128>() {
+      let (low, high) = self.bounds(id, part);
+      if let Some(high) = high.filter(|high| value >= *high) {
+        return Err(LedgerError::Rejected(format!(
+          "{} is not below known too high answer {}",
+          value, high
+        )));
+      }
+      if let Some(low) = low.filter(|low| value <= *low) {
+        return Err(LedgerError::Rejected(format!(
+          "{} is not above known too low answer {}",
+          value, low
+        )));
+      }
+    }
+    Ok(None)
+  }
+
+  /// The largest known too low and smallest known too high numeric answers.
+  pub fn bounds(&self, id: PuzzleId, part: Part) -> (Option<i128>, Option<i128>) {
+    let numeric = |verdict: Verdict| {
+      self
+        .attempts(id, part)
+        .filter(move |a| a.verdict == verdict)
+        .filter_map(|a| a.answer.parse::<i128>().ok())
+    };
+    (
+      numeric(Verdict::TooLow).max(),
+      numeric(Verdict::TooHigh).min(),
+    )
+  }
+
+  pub fn record(
+    &mut self,
+    id: PuzzleId,
+    part: Part,
+    answer: &str,
+    verdict: Verdict,
+  ) -> Result<(), LedgerError> {
+    let attempt = Attempt {
+      id,
+      part,
+      answer: answer.to_string(),
+      verdict,
+      timestamp: SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs()),
+    };
+    if let Some(dir) = self.path.parent() {
+      fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(&self.path)?;
+    writeln!(file, "{}", format_attempt(&attempt))?;
+    self.attempts.push(attempt);
+    Ok(())
+  }
+}
+
+fn format_attempt(attempt: &Attempt) -> String {
+  format!(
+    "{}\t{}\t{}\t{}\t{}",
+    attempt.timestamp,
+    attempt.id,
+    attempt.part.number(),
+    attempt.verdict,
+    attempt.answer
+  )
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+  let mut fields = line.splitn(5, '\t');
+  let timestamp = fields.next()?.parse().ok()?;
+  let mut id = fields.next()?.split('-');
+  let id = PuzzleId::new(id.next()?.parse().ok()?, id.next()?.parse().ok()?);
+  let part = fields.next()?.parse().ok()?;
+  let verdict = fields.next()?.parse().ok()?;
+  let answer = fields.next()?.to_string();
+  Some(Attempt {
+    id,
+    part,
+    answer,
+    verdict,
+    timestamp,
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use std::time::Duration;
+
+  use super::*;
+
+  fn ledger(name: &str) -> Ledger {
+    let path = std::env::temp_dir().join(format!(
+      "advent-of-rust-ledger-{}-{}",
+      name,
+      std::process::id()
+    ));
+    let _ = fs::remove_file(&path);
+    Ledger::load(&path).unwrap()
+  }
+
+  #[test]
+  fn test_record_and_reload() {
+    let id = PuzzleId::new(2020, 1);
+    let mut ledger = ledger("reload");
+    ledger.record(id, Part::One, "42", Verdict::TooLow).unwrap();
+    ledger
+      .record(id, Part::One, "50", Verdict::Wait(Duration::from_secs(30)))
+      .unwrap();
+    ledger
+      .record(id, Part::One, "100", Verdict::Correct)
+      .unwrap();
+
+    let reloaded = Ledger::load(&ledger.path).unwrap();
+    assert_eq!(reloaded.attempts, ledger.attempts);
+    assert_eq!(reloaded.correct(id, Part::One), Some("100"));
+    assert_eq!(reloaded.correct(id, Part::Two), None);
+  }
+
+  #[test]
+  fn test_check() {
+    let id = PuzzleId::new(2020, 1);
+    let mut ledger = ledger("check");
+    ledger.record(id, Part::One, "10", Verdict::TooLow).unwrap();
+    ledger
+      .record(id, Part::One, "90", Verdict::TooHigh)
+      .unwrap();
+    ledger.record(id, Part::One, "abc", Verdict::Wrong).unwrap();
+
+    assert_eq!(ledger.bounds(id, Part::One), (Some(10), Some(90)));
+    assert!(ledger.check(id, Part::One, "10").is_err());
+    assert!(ledger.check(id, Part::One, "5").is_err());
+    assert!(ledger.check(id, Part::One, "95").is_err());
+    assert!(ledger.check(id, Part::One, "abc").is_err());
+    assert_eq!(ledger.check(id, Part::One, "50").unwrap(), None);
+    assert_eq!(ledger.check(id, Part::Two, "5").unwrap(), None);
+
+    ledger
+      .record(id, Part::One, "50", Verdict::Correct)
+      .unwrap();
+    assert_eq!(
+      ledger.check(id, Part::One, "50").unwrap(),
+      Some(Verdict::Correct)
+    );
+    assert!(ledger.check(id, Part::One, "51").is_err());
+  }
+}
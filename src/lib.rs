@@ -1,3 +1,21 @@
+pub mod answers;
+pub mod automaton;
+pub mod config;
+pub mod dot;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod history;
 pub mod io;
+pub mod ledger;
+#[cfg(test)]
+mod mock_server;
 
+pub mod scaffold;
+pub mod solution;
 pub mod timing;
+pub mod vm;
+
+pub mod y2015;
+pub mod y2016;
+pub mod y2020;
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A request as seen by the `MockServer`, header names lowercased.
+#[derive(Debug, Clone)]
+pub struct Request {
+  pub method: String,
+  pub path: String,
+  pub headers: HashMap<String, String>,
+  pub body: String,
+}
+
+/// Minimal HTTP/1.1 stand-in for adventofcode.com, serving canned responses per `METHOD /path`.
+pub struct MockServer {
+  pub url: String,
+  requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+  pub fn start(routes: Vec<(&str, u16, &str)>) -> MockServer {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind mock server");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let routes: HashMap<String, (u16, String)> = routes
+      .into_iter()
+      .map(|(route, status, body)| (route.to_string(), (status, body.to_string())))
+      .collect();
+    let requests = Arc::new(Mutex::new(vec![]));
+    let recorded = Arc::clone(&requests);
+    thread::spawn(move || {
+      for stream in listener.incoming() {
+        let mut stream = match stream {
+          Ok(stream) => stream,
+          Err(_) => continue,
+        };
+        if let Some(request) = read_request(&stream) {
+          let route = format!("{} {}", request.method, request.path);
+          let (status, body) = routes
+            .get(&route)
+            .cloned()
+            .unwrap_or((404, "Not Found".to_string()));
+          recorded.lock().unwrap().push(request);
+          let _ = write!(
+            stream,
+            "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+          );
+        }
+      }
+    });
+    MockServer { url, requests }
+  }
+
+  pub fn requests(&self) -> Vec<Request> {
+    self.requests.lock().unwrap().clone()
+  }
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+  let mut reader = BufReader::new(stream);
+  let mut line = String::new();
+  reader.read_line(&mut line).ok()?;
+  let mut parts = line.split_whitespace();
+  let method = parts.next()?.to_string();
+  let path = parts.next()?.to_string();
+
+  let mut headers = HashMap::new();
+  loop {
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let line = line.trim_end();
+    if line.is_empty() {
+      break;
+    }
+    let pos = line.find(':')?;
+    headers.insert(
+      line[..pos].trim().to_lowercase(),
+      line[pos + 1..].trim().to_string(),
+    );
+  }
+
+  let length = headers
+    .get("content-length")
+    .and_then(|l| l.parse().ok())
+    .unwrap_or(0);
+  let mut body = vec![0; length];
+  reader.read_exact(&mut body).ok()?;
+  Some(Request {
+    method,
+    path,
+    headers,
+    body: String::from_utf8_lossy(&body).to_string(),
+  })
+}
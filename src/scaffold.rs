@@ -0,0 +1,259 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::solution::PuzzleId;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+  AlreadyExists(PathBuf),
+  Io(io::Error),
+}
+
+impl Display for ScaffoldError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+      ScaffoldError::Io(e) => write!(f, "failed to write the new day: {}", e),
+    }
+  }
+}
+
+impl std::error::Error for ScaffoldError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      ScaffoldError::Io(e) => Some(e),
+      _ => None,
+    }
+  }
+}
+
+impl From<io::Error> for ScaffoldError {
+  fn from(e: io::Error) -> Self {
+    ScaffoldError::Io(e)
+  }
+}
+
+/// Pulls the `<pre><code>` blocks out of a saved puzzle description, in page order.
+pub fn extract_examples(html: &str) -> Vec<String> {
+  lazy_static! {
+    static ref CODE: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    static ref TAG: Regex = Regex::new(r"</?[a-zA-Z][^>]*>").unwrap();
+  }
+  CODE
+    .captures_iter(html)
+    .map(|c| {
+      let text = TAG.replace_all(&c[1], "");
+      let text = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+      text.trim_end_matches('\n').to_string()
+    })
+    .collect()
+}
+
+pub fn render(id: PuzzleId, examples: &[String]) -> String {
+  let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+  let mut test_inputs = String::new();
+  if examples.is_empty() {
+    test_inputs.push_str("  const TEST_INPUT: &str = \"\";\n");
+  }
+  for (i, example) in examples.iter().enumerate() {
+    let name = match i {
+      0 => "TEST_INPUT".to_string(),
+      _ => format!("TEST_INPUT_{}", i + 1),
+    };
+    if i > 0 {
+      test_inputs.push_str("  #[allow(dead_code)]\n");
+    }
+    test_inputs.push_str(&format!(
+      "  const {}: &str = \"{}\";\n",
+      name,
+      escape(example)
+    ));
+  }
+  TEMPLATE
+    .replace("{{year}}", &id.year.to_string())
+    .replace("{{day_number}}", &id.day.to_string())
+    .replace("{{day}}", &format!("{:02}", id.day))
+    .replace("{{test_inputs}}", &test_inputs)
+}
+
+/// Inserts `line` among the existing lines starting with `prefix`, keeping them sorted.
+/// Appends after `anchor` when there are none yet.
+fn insert_sorted(contents: &str, prefix: &str, line: &str, anchor: &str) -> String {
+  let mut lines: Vec<&str> = contents.lines().collect();
+  if lines.contains(&line) {
+    return contents.to_string();
+  }
+  let matching: Vec<usize> = (0..lines.len())
+    .filter(|&i| lines[i].starts_with(prefix))
+    .collect();
+  let pos = match matching.iter().find(|&&i| lines[i] > line) {
+    Some(&i) => i,
+    None => match matching.last() {
+      Some(&i) => i + 1,
+      None => lines
+        .iter()
+        .position(|l| *l == anchor)
+        .map_or(lines.len(), |i| i + 1),
+    },
+  };
+  lines.insert(pos, line);
+  lines.join("\n") + "\n"
+}
+
+fn year_module(id: PuzzleId) -> String {
+  format!(
+    "use crate::solution::Registry;\n\npub mod day{day:02};\n\n\
+     pub fn register(registry: &mut Registry) {{\n  registry.register(day{day:02}::Day{day:02});\n}}\n",
+    day = id.day
+  )
+}
+
+/// Writes `src/yYYYY/dayDD.rs` under `root` and registers it with the year module,
+/// creating and registering the year module first if needed.
+pub fn create_day(
+  root: &Path,
+  id: PuzzleId,
+  examples: &[String],
+) -> Result<PathBuf, ScaffoldError> {
+  let year_dir = root.join("src").join(format!("y{}", id.year));
+  let day_mod = format!("day{:02}", id.day);
+  let day_path = year_dir.join(format!("{}.rs", day_mod));
+  if day_path.exists() {
+    return Err(ScaffoldError::AlreadyExists(day_path));
+  }
+
+  let mod_path = year_dir.join("mod.rs");
+  if mod_path.exists() {
+    let module = fs::read_to_string(&mod_path)?;
+    let module = insert_sorted(&module, "pub mod day", &format!("pub mod {};", day_mod), "");
+    let module = insert_sorted(
+      &module,
+      "  registry.register(",
+      &format!("  registry.register({}::Day{:02});", day_mod, id.day),
+      "pub fn register(registry: &mut Registry) {",
+    );
+    fs::write(&mod_path, module)?;
+  } else {
+    fs::create_dir_all(&year_dir)?;
+    fs::write(&mod_path, year_module(id))?;
+
+    let lib_path = root.join("src").join("lib.rs");
+    let lib = fs::read_to_string(&lib_path)?;
+    let line = format!("pub mod y{};", id.year);
+    fs::write(&lib_path, insert_sorted(&lib, "pub mod y", &line, ""))?;
+
+    let solution_path = root.join("src").join("solution.rs");
+    let solution = fs::read_to_string(&solution_path)?;
+    let line = format!("  crate::y{}::register(&mut registry);", id.year);
+    let anchor = "  let mut registry = Registry::new();";
+    fs::write(
+      &solution_path,
+      insert_sorted(&solution, "  crate::y", &line, anchor),
+    )?;
+  }
+
+  fs::write(&day_path, render(id, examples))?;
+  Ok(day_path)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_extract_examples() {
+    let html = "<article><p>For example:</p>\n<pre><code>1721\n979\n</code></pre>\n\
+      <p>then <code>inline</code></p><pre><code>a &lt;-&gt; <em>b</em> &amp; \"c\"\n</code></pre></article>";
+    assert_eq!(
+      extract_examples(html),
+      vec!["1721\n979".to_string(), "a <-> b & \"c\"".to_string()]
+    );
+    assert!(extract_examples("<p>nothing here</p>").is_empty());
+  }
+
+  #[test]
+  fn test_render() {
+    let rendered = render(PuzzleId::new(2021, 3), &["1\n\"2\"".to_string()]);
+    assert!(rendered.contains("pub struct Day03;"));
+    assert!(rendered.contains("impl Solution for Day03 {"));
+    assert!(rendered.contains("    2021\n"));
+    assert!(rendered.contains("    3\n"));
+    assert!(rendered.contains("  const TEST_INPUT: &str = \"1\n\\\"2\\\"\";\n"));
+    assert!(!rendered.contains("{{"));
+
+    let rendered = render(PuzzleId::new(2021, 3), &[]);
+    assert!(rendered.contains("  const TEST_INPUT: &str = \"\";\n"));
+  }
+
+  #[test]
+  fn test_insert_sorted() {
+    let module = "use crate::solution::Registry;\n\npub mod day01;\npub mod day03;\n";
+    assert_eq!(
+      insert_sorted(module, "pub mod day", "pub mod day02;", ""),
+      "use crate::solution::Registry;\n\npub mod day01;\npub mod day02;\npub mod day03;\n"
+    );
+    assert_eq!(
+      insert_sorted(module, "pub mod day", "pub mod day04;", ""),
+      "use crate::solution::Registry;\n\npub mod day01;\npub mod day03;\npub mod day04;\n"
+    );
+    assert_eq!(
+      insert_sorted(module, "pub mod day", "pub mod day01;", ""),
+      module
+    );
+  }
+
+  #[test]
+  fn test_create_day() {
+    let root = std::env::temp_dir().join(format!("advent-of-rust-scaffold-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(
+      root.join("src/lib.rs"),
+      "pub mod solution;\n\npub mod y2015;\npub mod y2020;\n",
+    )
+    .unwrap();
+    fs::write(
+      root.join("src/solution.rs"),
+      "pub fn registry() -> Registry {\n  let mut registry = Registry::new();\n  \
+       crate::y2015::register(&mut registry);\n  crate::y2020::register(&mut registry);\n  registry\n}\n",
+    )
+    .unwrap();
+
+    let path = create_day(&root, PuzzleId::new(2016, 2), &[]).unwrap();
+    assert_eq!(path, root.join("src/y2016/day02.rs"));
+    create_day(&root, PuzzleId::new(2016, 1), &[]).unwrap();
+    assert!(matches!(
+      create_day(&root, PuzzleId::new(2016, 1), &[]),
+      Err(ScaffoldError::AlreadyExists(_))
+    ));
+
+    assert_eq!(
+      fs::read_to_string(root.join("src/y2016/mod.rs")).unwrap(),
+      "use crate::solution::Registry;\n\npub mod day01;\npub mod day02;\n\n\
+       pub fn register(registry: &mut Registry) {\n  registry.register(day01::Day01);\n  \
+       registry.register(day02::Day02);\n}\n"
+    );
+    assert_eq!(
+      fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+      "pub mod solution;\n\npub mod y2015;\npub mod y2016;\npub mod y2020;\n"
+    );
+    assert!(fs::read_to_string(root.join("src/solution.rs"))
+      .unwrap()
+      .contains(
+        "crate::y2015::register(&mut registry);\n  crate::y2016::register(&mut registry);\n"
+      ));
+  }
+}
@@ -0,0 +1,334 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
+use crate::dot::Graph;
+use crate::io;
+use crate::io::InputError;
+use crate::timing::{benchmark_with, BenchmarkOptions, BenchmarkResult};
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct PuzzleId {
+  pub year: u16,
+  pub day: u8,
+}
+
+impl PuzzleId {
+  pub fn new(year: u16, day: u8) -> PuzzleId {
+    PuzzleId { year, day }
+  }
+}
+
+impl Display for PuzzleId {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{}-{:02}", self.year, self.day)
+  }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Part {
+  One,
+  Two,
+}
+
+impl FromStr for Part {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "1" => Ok(Part::One),
+      "2" => Ok(Part::Two),
+      _ => Err(format!("invalid part '{}', expected 1 or 2", s)),
+    }
+  }
+}
+
+impl Part {
+  pub fn number(self) -> u8 {
+    match self {
+      Part::One => 1,
+      Part::Two => 2,
+    }
+  }
+}
+
+impl Display for Part {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Part::One => write!(f, "part one"),
+      Part::Two => write!(f, "part two"),
+    }
+  }
+}
+
+pub trait Solution {
+  type Input;
+  type PartOne: Display;
+  type PartTwo: Display;
+
+  fn year(&self) -> u16;
+  fn day(&self) -> u8;
+  fn parse(&self, input: &str) -> Self::Input;
+  fn part_one(&self, input: &Self::Input) -> Self::PartOne;
+  fn part_two(&self, input: &Self::Input) -> Self::PartTwo;
+
+  /// A Graphviz view of the parsed input for `aoc run --dot`, on days where one helps.
+  fn dot(&self, _input: &Self::Input) -> Option<Graph> {
+    None
+  }
+
+  /// How the answers came about for `aoc run --explain`, on days with more to say than a number.
+  fn explain(&self, _input: &Self::Input) -> Option<String> {
+    None
+  }
+
+  /// Reads the cached puzzle input, panicking on failure. Meant for tests; tooling goes through `Puzzle::read_input`.
+  fn input(&self) -> String {
+    let id = PuzzleId::new(self.year(), self.day());
+    io::read_input(&id.to_string()).unwrap_or_else(|e| panic!("{}: {}", id, e))
+  }
+}
+
+#[derive(Debug)]
+pub struct Answer {
+  pub value: String,
+  pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub struct Report {
+  pub id: PuzzleId,
+  pub parse: Duration,
+  pub part_one: Option<Answer>,
+  pub part_two: Option<Answer>,
+}
+
+impl Report {
+  pub fn elapsed(&self) -> Duration {
+    self.parse
+      + self
+        .part_one
+        .as_ref()
+        .map_or(Duration::default(), |a| a.elapsed)
+      + self
+        .part_two
+        .as_ref()
+        .map_or(Duration::default(), |a| a.elapsed)
+  }
+}
+
+impl Display for Report {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(f, "{}", self.id)?;
+    write!(f, "parse {:?}", self.parse)?;
+    for (part, answer) in [(Part::One, &self.part_one), (Part::Two, &self.part_two)].iter() {
+      if let Some(answer) = answer {
+        write!(f, "\n{} {} {:?}", part, answer.value, answer.elapsed)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub struct BenchReport {
+  pub id: PuzzleId,
+  pub parse: BenchmarkResult,
+  pub part_one: Option<BenchmarkResult>,
+  pub part_two: Option<BenchmarkResult>,
+}
+
+impl Display for BenchReport {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(f, "{}", self.id)?;
+    write!(f, "parse {}", self.parse)?;
+    for (part, result) in [(Part::One, &self.part_one), (Part::Two, &self.part_two)].iter() {
+      if let Some(result) = result {
+        write!(f, "\n{} {}", part, result)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+/// Object safe view of a `Solution`, so days with different input and answer types can share a registry.
+pub trait Puzzle {
+  fn id(&self) -> PuzzleId;
+  fn read_input(&self, config: &Config) -> Result<String, InputError>;
+  fn run(&self, input: &str, part: Option<Part>) -> Report;
+  fn bench(&self, input: &str, part: Option<Part>, options: &BenchmarkOptions) -> BenchReport;
+  fn dot(&self, input: &str) -> Option<String>;
+  fn explain(&self, input: &str) -> Option<String>;
+}
+
+impl<S: Solution> Puzzle for S {
+  fn id(&self) -> PuzzleId {
+    PuzzleId::new(self.year(), self.day())
+  }
+
+  fn read_input(&self, config: &Config) -> Result<String, InputError> {
+    io::read_input_with(config, &self.id().to_string())
+  }
+
+  fn run(&self, input: &str, part: Option<Part>) -> Report {
+    let time = Instant::now();
+    let parsed = self.parse(input);
+    let parse = time.elapsed();
+
+    let part_one = if part != Some(Part::Two) {
+      let time = Instant::now();
+      let value = self.part_one(&parsed).to_string();
+      Some(Answer {
+        value,
+        elapsed: time.elapsed(),
+      })
+    } else {
+      None
+    };
+
+    let part_two = if part != Some(Part::One) {
+      let time = Instant::now();
+      let value = self.part_two(&parsed).to_string();
+      Some(Answer {
+        value,
+        elapsed: time.elapsed(),
+      })
+    } else {
+      None
+    };
+
+    Report {
+      id: self.id(),
+      parse,
+      part_one,
+      part_two,
+    }
+  }
+
+  fn bench(&self, input: &str, part: Option<Part>, options: &BenchmarkOptions) -> BenchReport {
+    let (parsed, parse) = benchmark_with(options, || self.parse(input));
+    let part_one = if part != Some(Part::Two) {
+      Some(benchmark_with(options, || self.part_one(&parsed)).1)
+    } else {
+      None
+    };
+    let part_two = if part != Some(Part::One) {
+      Some(benchmark_with(options, || self.part_two(&parsed)).1)
+    } else {
+      None
+    };
+    BenchReport {
+      id: self.id(),
+      parse,
+      part_one,
+      part_two,
+    }
+  }
+
+  fn dot(&self, input: &str) -> Option<String> {
+    Solution::dot(self, &self.parse(input)).map(|graph| graph.to_string())
+  }
+
+  fn explain(&self, input: &str) -> Option<String> {
+    Solution::explain(self, &self.parse(input))
+  }
+}
+
+#[derive(Default)]
+pub struct Registry {
+  puzzles: Vec<Box<dyn Puzzle>>,
+}
+
+impl Registry {
+  pub fn new() -> Self {
+    Registry { puzzles: vec![] }
+  }
+
+  pub fn register<P: Puzzle + 'static>(&mut self, puzzle: P) {
+    let id = puzzle.id();
+    if self.get(id).is_some() {
+      panic!("{} registered twice", id);
+    }
+    self.puzzles.push(Box::new(puzzle));
+    self.puzzles.sort_by_key(|p| p.id());
+  }
+
+  pub fn get(&self, id: PuzzleId) -> Option<&dyn Puzzle> {
+    self.iter().find(|p| p.id() == id)
+  }
+
+  pub fn year(&self, year: u16) -> impl Iterator<Item = &dyn Puzzle> {
+    self.iter().filter(move |p| p.id().year == year)
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &dyn Puzzle> {
+    self.puzzles.iter().map(|p| p.as_ref())
+  }
+}
+
+pub fn registry() -> Registry {
+  let mut registry = Registry::new();
+  crate::y2015::register(&mut registry);
+  crate::y2016::register(&mut registry);
+  crate::y2020::register(&mut registry);
+  registry
+}
+
+pub fn run(puzzle: &dyn Puzzle, config: &Config, part: Option<Part>) -> Result<Report, InputError> {
+  Ok(puzzle.run(&puzzle.read_input(config)?, part))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_puzzle_id_display() {
+    assert_eq!(PuzzleId::new(2020, 1).to_string(), "2020-01");
+    assert_eq!(PuzzleId::new(2015, 14).to_string(), "2015-14");
+  }
+
+  #[test]
+  fn test_registry() {
+    let registry = registry();
+    for &(year, day) in &[
+      (2015, 1),
+      (2015, 7),
+      (2015, 13),
+      (2016, 1),
+      (2020, 8),
+      (2020, 18),
+    ] {
+      let id = PuzzleId::new(year, day);
+      assert_eq!(registry.get(id).map(|p| p.id()), Some(id));
+    }
+    assert!(registry.get(PuzzleId::new(2014, 1)).is_none());
+    assert_eq!(registry.year(2014).count(), 0);
+    assert!(registry.year(2020).all(|p| p.id().year == 2020));
+    let ids: Vec<PuzzleId> = registry.iter().map(|p| p.id()).collect();
+    let mut sorted = ids.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(ids, sorted);
+  }
+
+  #[test]
+  fn test_part_from_str() {
+    assert_eq!("1".parse::<Part>(), Ok(Part::One));
+    assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+    assert!("3".parse::<Part>().is_err());
+  }
+
+  #[test]
+  fn test_run_part_filter() {
+    let day = crate::y2015::day01::Day01;
+    let report = day.run("(()", Some(Part::One));
+    assert_eq!(report.part_one.unwrap().value, "1");
+    assert!(report.part_two.is_none());
+    let report = day.run("())", None);
+    assert_eq!(report.part_one.unwrap().value, "-1");
+    assert_eq!(report.part_two.unwrap().value, "3");
+  }
+}
@@ -1,51 +1,174 @@
 use std::fmt;
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
 use std::time::{Duration, Instant};
 
+#[derive(Debug, Clone, Copy)]
+pub struct BenchmarkOptions {
+  /// How long to run the closure before measuring, to settle caches and the allocator.
+  pub warm_up: Duration,
+  /// Roughly how long all samples together should take.
+  pub measurement: Duration,
+  pub min_samples: usize,
+  pub max_samples: usize,
+}
+
+impl Default for BenchmarkOptions {
+  fn default() -> Self {
+    BenchmarkOptions {
+      warm_up: Duration::from_millis(200),
+      measurement: Duration::from_secs(1),
+      min_samples: 5,
+      max_samples: 100,
+    }
+  }
+}
+
+/// Per-iteration statistics over the samples that survived outlier rejection.
+#[derive(Debug, Clone, PartialEq)]
 pub struct BenchmarkResult {
-  times: u32,
-  total: Duration,
-  min: Duration,
-  max: Duration,
-  avg: Duration,
-  // mean: Duration,
+  pub samples: usize,
+  pub iterations: u64,
+  pub outliers: usize,
+  pub min: Duration,
+  pub max: Duration,
+  pub mean: Duration,
+  pub median: Duration,
+  pub stddev: Duration,
 }
 
-impl Debug for BenchmarkResult {
+impl Display for BenchmarkResult {
   fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-    f.debug_tuple("")
-      .field(&self.min)
-      .field(&self.avg)
-      .field(&self.max)
-      .field(&self.total)
-      .field(&self.times)
-      .finish()
+    write!(
+      f,
+      "{:?} ± {:?} (min {:?}, max {:?}, {} samples × {} iterations, {} outliers)",
+      self.median, self.stddev, self.min, self.max, self.samples, self.iterations, self.outliers
+    )
   }
 }
 
-pub fn benchmark<T>(times: u32, callback: impl Fn() -> T) -> (T, BenchmarkResult) {
-  let mut timer_list = vec![];
-  for _ in 0..times {
+pub fn benchmark<T>(callback: impl FnMut() -> T) -> (T, BenchmarkResult) {
+  benchmark_with(&BenchmarkOptions::default(), callback)
+}
+
+/// Warms up, picks an iteration count so each sample is long enough to time reliably,
+/// then measures. Returns the result of the first call along with the statistics.
+pub fn benchmark_with<T>(
+  options: &BenchmarkOptions,
+  mut callback: impl FnMut() -> T,
+) -> (T, BenchmarkResult) {
+  let start = Instant::now();
+  let result = callback();
+  let mut warm_up_runs = 1u32;
+  while start.elapsed() < options.warm_up {
+    black_box(callback());
+    warm_up_runs += 1;
+  }
+  let estimate = (start.elapsed() / warm_up_runs).max(Duration::from_nanos(1));
+
+  let max_samples = options.max_samples.max(options.min_samples);
+  let samples = ((options.measurement.as_nanos() / estimate.as_nanos()) as usize)
+    .max(options.min_samples)
+    .min(max_samples);
+  let per_sample = options.measurement / samples as u32;
+  let iterations = (per_sample.as_nanos() / estimate.as_nanos()).max(1) as u64;
+
+  let mut times = Vec::with_capacity(samples);
+  for _ in 0..samples {
     let start = Instant::now();
-    callback();
-    timer_list.push(start.elapsed().as_nanos())
+    for _ in 0..iterations {
+      black_box(callback());
+    }
+    times.push(start.elapsed().as_nanos() as f64 / iterations as f64);
   }
-  let sum = timer_list.iter().sum::<u128>();
-  let min = timer_list.iter().min().unwrap();
-  let max = timer_list.iter().max().unwrap();
-  let avg = sum / times as u128;
-  // let mean = (min + max) / 2;
+  (result, statistics(times, iterations))
+}
 
-  let result = callback();
-  (
-    result,
-    BenchmarkResult {
-      times,
-      total: Duration::from_nanos(sum as u64),
-      min: Duration::from_nanos(*min as u64),
-      max: Duration::from_nanos(*max as u64),
-      avg: Duration::from_nanos(avg as u64),
-      // mean: Duration::from_nanos(mean as u64),
-    },
-  )
+fn statistics(mut times: Vec<f64>, iterations: u64) -> BenchmarkResult {
+  times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+  let (low, high) = tukey_fences(&times);
+  let total = times.len();
+  let kept: Vec<f64> = times
+    .into_iter()
+    .filter(|t| *t >= low && *t <= high)
+    .collect();
+
+  let mean = kept.iter().sum::<f64>() / kept.len() as f64;
+  let variance = if kept.len() > 1 {
+    kept.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / (kept.len() - 1) as f64
+  } else {
+    0.0
+  };
+  let nanos = |n: f64| Duration::from_nanos(n.round() as u64);
+  BenchmarkResult {
+    samples: kept.len(),
+    iterations,
+    outliers: total - kept.len(),
+    min: nanos(kept[0]),
+    max: nanos(kept[kept.len() - 1]),
+    mean: nanos(mean),
+    median: nanos(quantile(&kept, 0.5)),
+    stddev: nanos(variance.sqrt()),
+  }
+}
+
+/// Samples outside `[Q1 - 1.5 IQR, Q3 + 1.5 IQR]` are treated as noise.
+fn tukey_fences(sorted: &[f64]) -> (f64, f64) {
+  let q1 = quantile(sorted, 0.25);
+  let q3 = quantile(sorted, 0.75);
+  let iqr = q3 - q1;
+  (q1 - 1.5 * iqr, q3 + 1.5 * iqr)
+}
+
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+  let pos = (sorted.len() - 1) as f64 * q;
+  let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+  sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_quantile() {
+    let sorted = [1.0, 2.0, 3.0, 4.0];
+    assert_eq!(quantile(&sorted, 0.0), 1.0);
+    assert_eq!(quantile(&sorted, 0.5), 2.5);
+    assert_eq!(quantile(&sorted, 1.0), 4.0);
+    assert_eq!(quantile(&[7.0], 0.5), 7.0);
+  }
+
+  #[test]
+  fn test_statistics() {
+    let result = statistics(vec![12.0, 10.0, 11.0, 10.0, 1000.0, 13.0, 11.0], 4);
+    assert_eq!(result.samples, 6);
+    assert_eq!(result.outliers, 1);
+    assert_eq!(result.iterations, 4);
+    assert_eq!(result.min, Duration::from_nanos(10));
+    assert_eq!(result.max, Duration::from_nanos(13));
+    assert_eq!(result.median, Duration::from_nanos(11));
+    assert_eq!(result.mean, Duration::from_nanos(11));
+    assert_eq!(result.stddev, Duration::from_nanos(1));
+  }
+
+  #[test]
+  fn test_benchmark_calibrates() {
+    let options = BenchmarkOptions {
+      warm_up: Duration::from_millis(5),
+      measurement: Duration::from_millis(20),
+      min_samples: 5,
+      max_samples: 10,
+    };
+    let mut calls = 0u64;
+    let (result, stats) = benchmark_with(&options, || {
+      calls += 1;
+      calls
+    });
+    assert_eq!(result, 1);
+    assert!(stats.samples + stats.outliers <= 10);
+    assert!(stats.samples + stats.outliers >= 5);
+    assert!(stats.iterations > 1);
+    assert!(stats.min <= stats.median && stats.median <= stats.max);
+  }
 }
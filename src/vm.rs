@@ -0,0 +1,527 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// The handheld console instruction set, plus whatever an [`Extension`] adds.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Op {
+  Acc,
+  Jmp,
+  Nop,
+  /// An op handled by an extension, by mnemonic.
+  Custom(&'static str),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Instruction {
+  pub op: Op,
+  pub arg: i64,
+}
+
+impl Instruction {
+  pub fn new(op: Op, arg: i64) -> Instruction {
+    Instruction { op, arg }
+  }
+
+  /// Parses an instruction that may use any of the `custom` mnemonics as well as the built-in
+  /// ones.
+  pub fn parse_with(s: &str, custom: &[&'static str]) -> Result<Instruction, String> {
+    let invalid = || format!("invalid instruction {:?}", s);
+    let (op, arg) = s.trim().split_once(' ').ok_or_else(invalid)?;
+    let op = match op {
+      "acc" => Op::Acc,
+      "jmp" => Op::Jmp,
+      "nop" => Op::Nop,
+      _ => Op::Custom(custom.iter().find(|&&c| c == op).ok_or_else(invalid)?),
+    };
+    let arg = arg.trim().parse().map_err(|_| invalid())?;
+    Ok(Instruction { op, arg })
+  }
+
+  /// Where control goes after running this at `index`, which may be outside the program.
+  /// Custom ops are assumed to fall through, since only their extension knows better.
+  pub fn successor(&self, index: usize) -> i64 {
+    match self.op {
+      Op::Jmp => index as i64 + self.arg,
+      Op::Acc | Op::Nop | Op::Custom(_) => index as i64 + 1,
+    }
+  }
+}
+
+impl FromStr for Instruction {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Instruction, String> {
+    Instruction::parse_with(s, &[])
+  }
+}
+
+impl Display for Instruction {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    let op = match self.op {
+      Op::Acc => "acc",
+      Op::Jmp => "jmp",
+      Op::Nop => "nop",
+      Op::Custom(op) => op,
+    };
+    write!(f, "{} {:+}", op, self.arg)
+  }
+}
+
+/// The register the built-in `acc` adds to.
+pub const ACC: usize = 0;
+
+/// A machine's registers, numbered from zero. The built-in ops only use [`ACC`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Registers {
+  values: Vec<i64>,
+}
+
+impl Registers {
+  /// `count` registers, all zero. There is always at least the accumulator.
+  pub fn new(count: usize) -> Registers {
+    Registers {
+      values: vec![0; count.max(1)],
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.values.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.values.is_empty()
+  }
+}
+
+impl Index<usize> for Registers {
+  type Output = i64;
+
+  fn index(&self, register: usize) -> &i64 {
+    &self.values[register]
+  }
+}
+
+impl IndexMut<usize> for Registers {
+  fn index_mut(&mut self, register: usize) -> &mut i64 {
+    &mut self.values[register]
+  }
+}
+
+/// Extra ops for puzzles that build on the handheld console.
+pub trait Extension {
+  /// The mnemonics this extension runs.
+  fn ops(&self) -> &[&'static str];
+
+  /// How many registers a machine running these ops needs.
+  fn registers(&self) -> usize {
+    1
+  }
+
+  /// Runs one of [`Extension::ops`] and returns how far to move the instruction pointer, which
+  /// is 1 to carry on with the next instruction.
+  fn execute(&self, op: &str, arg: i64, registers: &mut Registers) -> i64;
+}
+
+/// Replaces the instruction at `index`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Patch {
+  pub index: usize,
+  pub instruction: Instruction,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Program {
+  pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+  /// Parses one instruction per line, allowing the `custom` mnemonics of an extension.
+  pub fn parse_with(s: &str, custom: &[&'static str]) -> Result<Program, String> {
+    let instructions = s
+      .lines()
+      .filter(|l| !l.trim().is_empty())
+      .map(|l| Instruction::parse_with(l, custom))
+      .collect::<Result<Vec<Instruction>, String>>()?;
+    Ok(Program { instructions })
+  }
+
+  pub fn len(&self) -> usize {
+    self.instructions.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.instructions.is_empty()
+  }
+
+  pub fn get(&self, ip: i64) -> Option<&Instruction> {
+    usize::try_from(ip)
+      .ok()
+      .and_then(|i| self.instructions.get(i))
+  }
+
+  pub fn patched(&self, patch: &Patch) -> Program {
+    let mut program = self.clone();
+    program.instructions[patch.index] = patch.instruction;
+    program
+  }
+
+  /// Every patch that swaps a `jmp` for a `nop` or the other way round.
+  pub fn flips(&self) -> impl Iterator<Item = Patch> + '_ {
+    self
+      .instructions
+      .iter()
+      .enumerate()
+      .filter_map(move |(index, _)| self.flip(index))
+  }
+
+  /// Swaps the `jmp` or `nop` at `index`, or `None` for an `acc`.
+  pub fn flip(&self, index: usize) -> Option<Patch> {
+    let instruction = self.instructions[index];
+    let op = match instruction.op {
+      Op::Jmp => Op::Nop,
+      Op::Nop => Op::Jmp,
+      Op::Acc | Op::Custom(_) => return None,
+    };
+    Some(Patch {
+      index,
+      instruction: Instruction::new(op, instruction.arg),
+    })
+  }
+
+  /// Which instructions lead to termination, found by walking the control-flow graph backwards
+  /// from just past the last instruction.
+  pub fn halting(&self) -> Vec<bool> {
+    let len = self.len();
+    let mut predecessors: Vec<Vec<usize>> = vec![vec![]; len + 1];
+    for index in 0..len {
+      let next = self.instructions[index].successor(index);
+      if (0..=len as i64).contains(&next) {
+        predecessors[next as usize].push(index);
+      }
+    }
+    let mut halting = vec![false; len + 1];
+    halting[len] = true;
+    let mut stack = vec![len];
+    while let Some(node) = stack.pop() {
+      for &pred in &predecessors[node] {
+        if !halting[pred] {
+          halting[pred] = true;
+          stack.push(pred);
+        }
+      }
+    }
+    halting.truncate(len);
+    halting
+  }
+
+  /// The single flip that makes the program halt, with the accumulator when it does. Only the
+  /// instructions the unpatched program runs before looping can be the culprit, and flipping
+  /// one of them helps exactly when its new successor leads to termination, so this takes
+  /// linear time instead of one run per candidate.
+  pub fn repair(&self) -> Option<(Patch, i64)> {
+    let halting = self.halting();
+    let leads_to_halt =
+      |ip: i64| ip == self.len() as i64 || self.get(ip).is_some() && halting[ip as usize];
+    let mut machine = Machine::new(self).with_trace_limit(self.len());
+    machine.run();
+    let patch = machine
+      .trace()
+      .iter()
+      .filter_map(|&index| self.flip(index))
+      .find(|patch| leads_to_halt(patch.instruction.successor(patch.index)))?;
+    self.find_patch(Some(patch))
+  }
+
+  /// The first of `patches` that makes the program halt, with the accumulator at that point.
+  pub fn find_patch(&self, patches: impl IntoIterator<Item = Patch>) -> Option<(Patch, i64)> {
+    patches.into_iter().find_map(|patch| {
+      let program = self.patched(&patch);
+      let mut machine = Machine::new(&program);
+      match machine.run() {
+        Exit::Halted => Some((patch, machine.acc())),
+        _ => None,
+      }
+    })
+  }
+}
+
+/// One instruction per line.
+impl FromStr for Program {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Program, String> {
+    Program::parse_with(s, &[])
+  }
+}
+
+/// Why a run stopped.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Exit {
+  /// Jumped to just past the last instruction.
+  Halted,
+  /// About to run an instruction a second time.
+  Looped,
+  /// Jumped anywhere else outside the program.
+  OutOfBounds,
+  /// About to run a custom op the machine has no extension for.
+  Unsupported,
+}
+
+/// How many executed instructions a machine remembers by default.
+pub const TRACE_LIMIT: usize = 1 << 16;
+
+#[derive(Clone)]
+pub struct Machine<'a> {
+  program: &'a Program,
+  extension: Option<&'a dyn Extension>,
+  pub ip: i64,
+  pub registers: Registers,
+  trace: Vec<usize>,
+  trace_limit: usize,
+}
+
+impl<'a> Machine<'a> {
+  pub fn new(program: &'a Program) -> Machine<'a> {
+    Machine {
+      program,
+      extension: None,
+      ip: 0,
+      registers: Registers::new(1),
+      trace: vec![],
+      trace_limit: TRACE_LIMIT,
+    }
+  }
+
+  /// Keeps only the last `limit` executed instructions in the trace.
+  pub fn with_trace_limit(self, limit: usize) -> Machine<'a> {
+    Machine {
+      trace_limit: limit,
+      ..self
+    }
+  }
+
+  /// A machine that runs the ops of `extension` too, with as many registers as it needs.
+  pub fn with_extension(program: &'a Program, extension: &'a dyn Extension) -> Machine<'a> {
+    Machine {
+      extension: Some(extension),
+      registers: Registers::new(extension.registers()),
+      ..Machine::new(program)
+    }
+  }
+
+  pub fn acc(&self) -> i64 {
+    self.registers[ACC]
+  }
+
+  /// Indices of the most recently executed instructions, in order, at most the trace limit.
+  pub fn trace(&self) -> &[usize] {
+    &self.trace[self.trace.len().saturating_sub(self.trace_limit)..]
+  }
+
+  /// The next instruction, or why there is none.
+  pub fn next_instruction(&self) -> Result<Instruction, Exit> {
+    match self.program.get(self.ip) {
+      Some(Instruction {
+        op: Op::Custom(op), ..
+      }) if !self.extension.is_some_and(|e| e.ops().contains(op)) => Err(Exit::Unsupported),
+      Some(instruction) => Ok(*instruction),
+      None if self.ip == self.program.len() as i64 => Err(Exit::Halted),
+      None => Err(Exit::OutOfBounds),
+    }
+  }
+
+  /// Executes one instruction.
+  pub fn step(&mut self) -> Result<(), Exit> {
+    let instruction = self.next_instruction()?;
+    if self.trace.len() >= self.trace_limit.max(1) * 2 {
+      self.trace.drain(..self.trace.len() - self.trace_limit);
+    }
+    self.trace.push(self.ip as usize);
+    self.ip += match instruction.op {
+      Op::Acc => {
+        self.registers[ACC] += instruction.arg;
+        1
+      }
+      Op::Jmp => instruction.arg,
+      Op::Nop => 1,
+      Op::Custom(op) => {
+        self
+          .extension
+          .ok_or(Exit::Unsupported)?
+          .execute(op, instruction.arg, &mut self.registers)
+      }
+    };
+    Ok(())
+  }
+
+  /// Steps until the program stops or `stop` returns true before an instruction is executed.
+  /// Returns `None` when `stop` ended the run.
+  pub fn run_until(&mut self, mut stop: impl FnMut(&Machine) -> bool) -> Option<Exit> {
+    loop {
+      if let Err(exit) = self.next_instruction() {
+        return Some(exit);
+      }
+      if stop(self) {
+        return None;
+      }
+      self.step().ok()?;
+    }
+  }
+
+  /// Runs until the program stops or loops.
+  ///
+  /// The built-in ops never branch on a register, so without an extension the machine has
+  /// looped as soon as it is about to repeat an instruction. Extension ops can, so then it has
+  /// only looped once it is back at an instruction with the same registers.
+  pub fn run(&mut self) -> Exit {
+    let looped = if self.extension.is_some() {
+      let mut seen = HashSet::new();
+      self.run_until(|machine| !seen.insert((machine.ip, machine.registers.clone())))
+    } else {
+      let mut seen = HashSet::new();
+      self.run_until(|machine| !seen.insert(machine.ip))
+    };
+    looped.unwrap_or(Exit::Looped)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TEST_INPUT: &str = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+  #[test]
+  fn test_parse() {
+    let program: Program = TEST_INPUT.parse().unwrap();
+    assert_eq!(program.len(), 9);
+    assert_eq!(program.instructions[4], Instruction::new(Op::Jmp, -3));
+    assert_eq!(program.instructions[4].to_string(), "jmp -3");
+    assert!("jmp".parse::<Instruction>().is_err());
+    assert!("mul +2".parse::<Instruction>().is_err());
+  }
+
+  #[test]
+  fn test_run() {
+    let program: Program = TEST_INPUT.parse().unwrap();
+    let mut machine = Machine::new(&program);
+    assert_eq!(machine.run(), Exit::Looped);
+    assert_eq!(machine.acc(), 5);
+    assert_eq!(machine.trace(), &[0, 1, 2, 6, 7, 3, 4]);
+    assert_eq!(machine.ip, 1);
+  }
+
+  #[test]
+  fn test_trace_limit() {
+    let program: Program = "nop +0\njmp -1".parse().unwrap();
+    let mut machine = Machine::new(&program).with_trace_limit(3);
+    assert_eq!(machine.run_until(|m| m.trace().len() == 3), None);
+    assert_eq!(machine.trace(), &[0, 1, 0]);
+    for _ in 0..100 {
+      machine.step().unwrap();
+    }
+    assert_eq!(machine.trace(), &[0, 1, 0]);
+    assert!(machine.trace.len() <= 6);
+  }
+
+  #[test]
+  fn test_run_until() {
+    let program: Program = TEST_INPUT.parse().unwrap();
+    let mut machine = Machine::new(&program);
+    assert_eq!(machine.run_until(|m| m.acc() == 2), None);
+    assert_eq!(machine.ip, 7);
+    let program: Program = "jmp +2\nnop +0".parse().unwrap();
+    assert_eq!(Machine::new(&program).run(), Exit::Halted);
+    let program: Program = "jmp -1".parse().unwrap();
+    assert_eq!(Machine::new(&program).run(), Exit::OutOfBounds);
+  }
+
+  #[test]
+  fn test_patch() {
+    let program: Program = TEST_INPUT.parse().unwrap();
+    assert_eq!(program.flips().count(), 4);
+    let (patch, acc) = program.find_patch(program.flips()).unwrap();
+    assert_eq!(patch.index, 7);
+    assert_eq!(patch.instruction, Instruction::new(Op::Nop, -4));
+    assert_eq!(acc, 8);
+  }
+
+  #[test]
+  fn test_repair() {
+    let program: Program = TEST_INPUT.parse().unwrap();
+    assert_eq!(
+      program.halting(),
+      vec![false, false, false, false, false, false, false, false, true]
+    );
+    let (patch, acc) = program.repair().unwrap();
+    assert_eq!(patch.index, 7);
+    assert_eq!(acc, 8);
+    let program: Program = "jmp +0\nacc +1".parse().unwrap();
+    assert_eq!(
+      program.repair().map(|(p, acc)| (p.index, acc)),
+      Some((0, 1))
+    );
+    let program: Program = "acc +1\njmp -1\njmp -1".parse().unwrap();
+    assert_eq!(program.repair(), None);
+  }
+
+  /// `set` loads register 1, `add` adds it to the accumulator and `jnz` jumps when the
+  /// accumulator is not zero.
+  struct Counter;
+
+  impl Extension for Counter {
+    fn ops(&self) -> &[&'static str] {
+      &["set", "add", "jnz"]
+    }
+
+    fn registers(&self) -> usize {
+      2
+    }
+
+    fn execute(&self, op: &str, arg: i64, registers: &mut Registers) -> i64 {
+      match op {
+        "set" => registers[1] = arg,
+        "add" => registers[ACC] += registers[1],
+        "jnz" if registers[ACC] != 0 => return arg,
+        _ => {}
+      }
+      1
+    }
+  }
+
+  #[test]
+  fn test_extension() {
+    let source = "acc -6\nset +2\nadd +0\njnz -1\nacc +7";
+    assert!(source.parse::<Program>().is_err());
+    let program = Program::parse_with(source, Counter.ops()).unwrap();
+    assert_eq!(
+      program.instructions[1],
+      Instruction::new(Op::Custom("set"), 2)
+    );
+    assert_eq!(program.instructions[3].to_string(), "jnz -1");
+    let mut machine = Machine::with_extension(&program, &Counter);
+    assert_eq!(machine.run(), Exit::Halted);
+    assert_eq!(machine.acc(), 7);
+    assert_eq!(machine.registers[1], 2);
+    assert_eq!(machine.trace(), &[0, 1, 2, 3, 2, 3, 2, 3, 4]);
+    let mut machine = Machine::new(&program);
+    assert_eq!(machine.run(), Exit::Unsupported);
+    assert_eq!(machine.ip, 1);
+    let program = Program::parse_with("acc +1\njnz +0", Counter.ops()).unwrap();
+    let mut machine = Machine::with_extension(&program, &Counter);
+    assert_eq!(machine.run(), Exit::Looped);
+    assert_eq!(machine.trace(), &[0, 1]);
+  }
+}
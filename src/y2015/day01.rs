@@ -0,0 +1,81 @@
+use crate::solution::Solution;
+
+pub struct Day01;
+
+fn parse_input(input: &str) -> Vec<i32> {
+  input
+    .chars()
+    .map(|c| -> i32 {
+      match c {
+        '(' => 1,
+        ')' => -1,
+        _ => 0,
+      }
+    })
+    .collect()
+}
+
+fn part_one(directions: &[i32]) -> i32 {
+  directions.iter().sum()
+}
+
+fn part_two(directions: &[i32]) -> usize {
+  directions
+    .iter()
+    .scan(0, |acc, &n| {
+      *acc += n;
+      Some(*acc)
+    })
+    .take_while(|x| x != &-1)
+    .count()
+    + 1
+}
+
+impl Solution for Day01 {
+  type Input = Vec<i32>;
+  type PartOne = i32;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    1
+  }
+
+  fn parse(&self, input: &str) -> Vec<i32> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<i32>) -> i32 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<i32>) -> usize {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_directions_to_int() {
+    assert_eq!(parse_input("(())"), vec![1, 1, -1, -1]);
+  }
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input("(())")), 0);
+    assert_eq!(part_one(&parse_input(&Day01.input())), 138);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(")")), 1);
+    assert_eq!(part_two(&parse_input("()())")), 5);
+    assert_eq!(part_two(&parse_input(&Day01.input())), 1771);
+  }
+}
@@ -1,16 +1,15 @@
-use common::io;
-
-use std::string::ParseError;
-
 use std::str::FromStr;
+use std::string::ParseError;
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
-use std::time::Instant;
+use crate::solution::Solution;
+
+pub struct Day02;
 
 #[derive(Debug, PartialEq, Eq)]
-struct Dimension {
+pub struct Dimension {
   l: u32,
   w: u32,
   h: u32,
@@ -37,7 +36,7 @@ impl FromStr for Dimension {
 }
 impl Dimension {
   fn calc_paper(&self) -> u32 {
-    let sides = vec![&self.l * &self.w, &self.w * &self.h, &self.h * &self.l];
+    let sides = [self.l * self.w, self.w * self.h, self.h * self.l];
     let min = sides.iter().min();
     (2 * sides[0] + 2 * sides[1] + 2 * sides[2]) + min.unwrap()
   }
@@ -45,14 +44,10 @@ impl Dimension {
   fn calc_ribbon(&self) -> u32 {
     let mut sides: Vec<u32> = vec![self.l, self.w, self.h];
     sides.sort();
-    return (sides[0] * 2) + (sides[1] * 2) + (&self.l * &self.w * &self.h);
+    (sides[0] * 2) + (sides[1] * 2) + (self.l * self.w * self.h)
   }
 }
 
-fn read_input() -> String {
-  return io::read_input("2015-02");
-}
-
 fn parse_input(input: &str) -> Vec<Dimension> {
   input
     .lines()
@@ -60,24 +55,38 @@ fn parse_input(input: &str) -> Vec<Dimension> {
     .collect()
 }
 
-fn part_one(input: &str) -> u32 {
-  parse_input(input).iter().map(|dim| dim.calc_paper()).sum()
+fn part_one(dimensions: &[Dimension]) -> u32 {
+  dimensions.iter().map(|dim| dim.calc_paper()).sum()
 }
 
-fn part_two(input: &str) -> u32 {
-  parse_input(input).iter().map(|dim| dim.calc_ribbon()).sum()
+fn part_two(dimensions: &[Dimension]) -> u32 {
+  dimensions.iter().map(|dim| dim.calc_ribbon()).sum()
 }
 
-fn main() {
-  let input = read_input();
-  let time = Instant::now();
-  let p1 = part_one(&input);
-  let p1_time = time.elapsed();
-  let time = Instant::now();
-  let p2 = part_two(&input);
-  let p2_time = time.elapsed();
-  println!("part one {:?} {:?}", p1, p1_time);
-  println!("part two {:?} {:?}", p2, p2_time);
+impl Solution for Day02 {
+  type Input = Vec<Dimension>;
+  type PartOne = u32;
+  type PartTwo = u32;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    2
+  }
+
+  fn parse(&self, input: &str) -> Vec<Dimension> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<Dimension>) -> u32 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<Dimension>) -> u32 {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -110,13 +119,13 @@ mod test {
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one("2x3x4\n1x1x10"), 58 + 43);
-    assert_eq!(part_one(&read_input()), 1606483);
+    assert_eq!(part_one(&parse_input("2x3x4\n1x1x10")), 58 + 43);
+    assert_eq!(part_one(&parse_input(&Day02.input())), 1606483);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two("2x3x4\n1x1x10"), 34 + 14);
-    assert_eq!(part_two(&read_input()), 3842356);
+    assert_eq!(part_two(&parse_input("2x3x4\n1x1x10")), 34 + 14);
+    assert_eq!(part_two(&parse_input(&Day02.input())), 3842356);
   }
 }
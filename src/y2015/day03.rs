@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use crate::geom::{Heading, Point2};
+use crate::solution::Solution;
+
+pub struct Day03;
+
+fn parse_input(input: &str) -> Vec<Heading> {
+  input
+    .trim()
+    .chars()
+    .map(|c| Heading::from_char(c).expect("invalid direction"))
+    .collect()
+}
+
+fn part_one(directions: &[Heading]) -> usize {
+  let unique: HashSet<_> = directions
+    .iter()
+    .scan(Point2::origin(), |acc, dir| {
+      *acc += dir.delta();
+      Some(*acc)
+    })
+    .collect();
+  unique.len()
+}
+
+fn part_two(directions: &[Heading]) -> usize {
+  let initial_state = (0, Point2::origin(), Point2::origin());
+  let points_visited: Vec<(usize, Point2, Point2)> = directions
+    .iter()
+    .scan(initial_state, |acc, dir| {
+      let (step, santa, robo) = *acc;
+      if step % 2 == 0 {
+        *acc = (step + 1, santa + dir.delta(), robo);
+      } else {
+        *acc = (step + 1, santa, robo + dir.delta());
+      }
+      Some(*acc)
+    })
+    .collect();
+  let unique: HashSet<Point2> = points_visited.iter().flat_map(|t| vec![t.1, t.2]).collect();
+  unique.len()
+}
+
+impl Solution for Day03 {
+  type Input = Vec<Heading>;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    3
+  }
+
+  fn parse(&self, input: &str) -> Vec<Heading> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<Heading>) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<Heading>) -> usize {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_parse_input() {
+    assert_eq!(parse_input("<"), vec![Heading::West]);
+    assert_eq!(
+      parse_input("<>^v"),
+      vec![Heading::West, Heading::East, Heading::North, Heading::South]
+    );
+  }
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(">")), 1);
+    assert_eq!(part_one(&parse_input("^>v<")), 4);
+    assert_eq!(part_one(&parse_input("^v^v^v^v^v")), 2);
+    assert_eq!(part_one(&parse_input(&Day03.input())), 2572);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input("^v")), 3);
+    assert_eq!(part_two(&parse_input("^>v<")), 3);
+    assert_eq!(part_two(&parse_input("^v^v^v^v^v")), 11);
+    assert_eq!(part_two(&parse_input(&Day03.input())), 2631);
+  }
+}
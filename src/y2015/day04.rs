@@ -0,0 +1,74 @@
+use crate::solution::Solution;
+
+pub struct Day04;
+
+fn compute_hash(key: &str, i: &usize) -> String {
+  format!("{:x}", md5::compute(format!("{}{}", key, i)))
+}
+
+fn find_number_generating_hash(key: &str, starting_with: &str) -> usize {
+  let mut i = 1;
+  loop {
+    let hash = compute_hash(key, &i);
+    if hash.starts_with(starting_with) {
+      break;
+    }
+    i += 1;
+  }
+  i
+}
+
+fn parse_input(input: &str) -> String {
+  input.trim().to_string()
+}
+
+fn part_one(key: &str) -> usize {
+  find_number_generating_hash(key, "00000")
+}
+
+fn part_two(key: &str) -> usize {
+  find_number_generating_hash(key, "000000")
+}
+
+impl Solution for Day04 {
+  type Input = String;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    4
+  }
+
+  fn parse(&self, input: &str) -> String {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &String) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &String) -> usize {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  #[ignore]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(&Day04.input())), 254575);
+  }
+
+  #[test]
+  #[ignore]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(&Day04.input())), 1038736);
+  }
+}
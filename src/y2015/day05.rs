@@ -1,30 +1,17 @@
-use std::time::Instant;
+use crate::solution::Solution;
 
-use common::io;
+pub struct Day05;
 
 fn is_vowel(ch: char) -> bool {
-  return match ch {
-    'a' => true,
-    'e' => true,
-    'i' => true,
-    'o' => true,
-    'u' => true,
-    _ => false,
-  };
+  matches!(ch, 'a' | 'e' | 'i' | 'o' | 'u')
 }
 
 fn is_allowed(string: &str) -> bool {
-  return match string {
-    "ab" => false,
-    "cd" => false,
-    "pq" => false,
-    "xy" => false,
-    _ => true,
-  };
+  !matches!(string, "ab" | "cd" | "pq" | "xy")
 }
 
 fn is_xx(xx: &str) -> bool {
-  return xx.len() == 2 && xx.chars().nth(0) == xx.chars().nth(1);
+  xx.len() == 2 && xx.chars().next() == xx.chars().nth(1)
 }
 
 fn is_nice(string: &str) -> bool {
@@ -47,7 +34,7 @@ fn is_nice(string: &str) -> bool {
     }
     prev = (index, c);
   }
-  return vowels >= 3 && consecutive >= 1;
+  vowels >= 3 && consecutive >= 1
 }
 
 struct LetterPair {
@@ -78,46 +65,57 @@ fn is_nice_2(string: &str) -> bool {
   }
   for combo in &combos {
     for combo2 in &combos {
-      if combo.index < combo2.index && combo2.index - 1 > combo.index {
-        if combo2.string == combo.string {
-          has_repeat = true;
-          break;
-        }
+      if combo.index < combo2.index
+        && combo2.index - 1 > combo.index
+        && combo2.string == combo.string
+      {
+        has_repeat = true;
+        break;
       }
     }
     if has_repeat {
       break;
     }
   }
-  return has_pattern && has_repeat;
+  has_pattern && has_repeat
 }
 
-fn read_input() -> String {
-  return io::read_input("2015-05");
+fn parse_input(input: &str) -> Vec<String> {
+  input.split_whitespace().map(|s| s.to_string()).collect()
 }
 
-fn part_one(input: &str) -> usize {
-  return input.split_whitespace().filter(|s| is_nice(s)).count();
+fn part_one(strings: &[String]) -> usize {
+  strings.iter().filter(|s| is_nice(s)).count()
 }
 
-fn part_two(input: &str) -> usize {
-  return input.split_whitespace().filter(|s| is_nice_2(s)).count();
+fn part_two(strings: &[String]) -> usize {
+  strings.iter().filter(|s| is_nice_2(s)).count()
 }
 
-fn main() {
-  let input = &read_input();
-  let timer = Instant::now();
-  println!(
-    "part one {} {}ms",
-    part_one(input),
-    timer.elapsed().as_millis()
-  );
-  println!(
-    "part two {} {}ms",
-    part_two(input),
-    timer.elapsed().as_millis()
-  );
-  println!("total {}ms", timer.elapsed().as_millis());
+impl Solution for Day05 {
+  type Input = Vec<String>;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    5
+  }
+
+  fn parse(&self, input: &str) -> Vec<String> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<String>) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<String>) -> usize {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -126,7 +124,7 @@ mod test {
 
   #[test]
   fn test_is_vowel() {
-    "aeiou".chars().for_each(|c| assert!(is_vowel(c), true));
+    "aeiou".chars().for_each(|c| assert!(is_vowel(c)));
     "bcdfghjklmnpqrstvwxzy"
       .chars()
       .for_each(|c| assert!(!is_vowel(c)));
@@ -134,48 +132,48 @@ mod test {
 
   #[test]
   fn test_is_allowed() {
-    vec!["ab", "cd", "pq", "xy"]
+    ["ab", "cd", "pq", "xy"]
       .iter()
       .for_each(|s| assert!(!is_allowed(s)));
   }
 
   #[test]
   fn test_is_xx() {
-    vec!["xx", "yy", "aa", "bb", "dd"]
+    ["xx", "yy", "aa", "bb", "dd"]
       .iter()
       .for_each(|s| assert!(is_xx(s)));
-    vec!["xy", "bc", "ax", "ay", "la", "abcd"]
+    ["xy", "bc", "ax", "ay", "la", "abcd"]
       .iter()
       .for_each(|s| assert!(!is_xx(s)));
   }
 
   #[test]
   fn test_is_nice() {
-    vec!["ugknbfddgicrmopn", "aaa"]
+    ["ugknbfddgicrmopn", "aaa"]
       .iter()
       .for_each(|s| assert!(is_nice(s)));
-    vec!["jchzalrnumimnmhp", "haegwjzuvuyypxyu", "dvszwmarrgswjxmb"]
+    ["jchzalrnumimnmhp", "haegwjzuvuyypxyu", "dvszwmarrgswjxmb"]
       .iter()
       .for_each(|s| assert!(!is_nice(s)));
   }
 
   #[test]
   fn test_is_nice_2() {
-    vec!["qjhvhtzxzqqjkmpb", "xxyxx"]
+    ["qjhvhtzxzqqjkmpb", "xxyxx"]
       .iter()
       .for_each(|s| assert!(is_nice_2(s)));
-    vec!["uurcxstgmygtbstg", "ieodomkazucvgmuy"]
+    ["uurcxstgmygtbstg", "ieodomkazucvgmuy"]
       .iter()
       .for_each(|s| assert!(!is_nice_2(s)));
   }
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(&read_input()), 255);
+    assert_eq!(part_one(&parse_input(&Day05.input())), 255);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(&read_input()), 55);
+    assert_eq!(part_two(&parse_input(&Day05.input())), 55);
   }
 }
@@ -1,11 +1,12 @@
-use std::time::Instant;
-
 use lazy_static::lazy_static;
 use regex::Regex;
 
-use common::io;
+use crate::grid::Grid;
+use crate::solution::Solution;
 use Action::{Toggle, TurnOff, TurnOn};
 
+pub struct Day06;
+
 #[derive(Debug, PartialEq, Eq)]
 enum Action {
   TurnOn,
@@ -25,7 +26,7 @@ impl Action {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Instruction {
+pub struct Instruction {
   row_start: usize,
   col_start: usize,
   row_end: usize,
@@ -51,45 +52,37 @@ impl Instruction {
   }
 }
 
-fn read_input() -> String {
-  return io::read_input("2015-06");
-}
-
 fn parse_instructions(input: &str) -> Vec<Instruction> {
-  return input
-    .lines()
-    .map(|line| Instruction::from_string(line))
-    .collect();
+  input.lines().map(Instruction::from_string).collect()
 }
 
-type Grid = [[i64; 1000]];
+const SIZE: usize = 1000;
 
-fn mutate_grid(grid: &mut Grid, instructions: Vec<Instruction>, p1: bool) {
+fn mutate_grid(grid: &mut Grid<i64>, instructions: &[Instruction], p1: bool) {
   for instruction in instructions {
-    for y in instruction.row_start..instruction.row_end {
-      for x in instruction.col_start..instruction.col_end {
+    for row in instruction.row_start..instruction.row_end {
+      for col in instruction.col_start..instruction.col_end {
+        let light = &mut grid[(col, row)];
         match instruction.action {
           TurnOn => {
-            if p1 == true {
-              grid[y][x] = 1;
+            if p1 {
+              *light = 1;
             } else {
-              grid[y][x] += 1;
+              *light += 1;
             }
           }
           TurnOff => {
-            if p1 == true {
-              grid[y][x] = 0;
-            } else {
-              let cur = grid[y][x];
-              grid[y][x] -= if cur > 0 { 1 } else { 0 };
+            if p1 {
+              *light = 0;
+            } else if *light > 0 {
+              *light -= 1;
             }
           }
           Toggle => {
-            if p1 == true {
-              let cur = grid[y][x];
-              grid[y][x] = if cur == 0 { 1 } else { 0 };
+            if p1 {
+              *light = if *light == 0 { 1 } else { 0 };
             } else {
-              grid[y][x] += 2;
+              *light += 2;
             }
           }
         }
@@ -98,34 +91,42 @@ fn mutate_grid(grid: &mut Grid, instructions: Vec<Instruction>, p1: bool) {
   }
 }
 
-fn part_one(input: &str) -> usize {
-  let instructions = parse_instructions(&input);
-  let mut grid = vec![[0; 1000]; 1000];
+fn part_one(instructions: &[Instruction]) -> usize {
+  let mut grid = Grid::new(SIZE, SIZE, 0);
   mutate_grid(&mut grid, instructions, true);
-  return grid.iter().flatten().filter(|light| **light == 1).count();
+  grid.iter().filter(|light| **light == 1).count()
 }
 
-fn part_two(input: &str) -> i64 {
-  let instructions = parse_instructions(&input);
-  let mut grid = vec![[0; 1000]; 1000];
+fn part_two(instructions: &[Instruction]) -> i64 {
+  let mut grid = Grid::new(SIZE, SIZE, 0);
   mutate_grid(&mut grid, instructions, false);
-  return grid.iter().flatten().sum();
+  grid.iter().sum()
 }
 
-fn main() {
-  let input = read_input();
-  let timer = Instant::now();
-  println!(
-    "part one {} {}ms",
-    part_one(&input),
-    timer.elapsed().as_millis()
-  );
-  println!(
-    "part two {} {}ms",
-    part_two(&input),
-    timer.elapsed().as_millis()
-  );
-  println!("total {}ms", timer.elapsed().as_millis())
+impl Solution for Day06 {
+  type Input = Vec<Instruction>;
+  type PartOne = usize;
+  type PartTwo = i64;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    6
+  }
+
+  fn parse(&self, input: &str) -> Vec<Instruction> {
+    parse_instructions(input)
+  }
+
+  fn part_one(&self, input: &Vec<Instruction>) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<Instruction>) -> i64 {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -148,11 +149,11 @@ mod test {
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(&read_input()), 543903);
+    assert_eq!(part_one(&parse_instructions(&Day06.input())), 543903);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(&read_input()), 14687245);
+    assert_eq!(part_two(&parse_instructions(&Day06.input())), 14687245);
   }
 }
@@ -0,0 +1,563 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::string::ParseError;
+
+use crate::dot::Graph;
+use crate::graph::Digraph;
+use crate::solution::Solution;
+
+use Op::{And, Assign, Lshift, Not, Or, Rshift};
+
+pub struct Day07;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+enum Op {
+  Assign,
+  And,
+  Or,
+  Not,
+  Lshift,
+  Rshift,
+}
+
+impl FromStr for Op {
+  type Err = ParseError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut op = Op::Assign;
+    ["NOT", "AND", "OR", "LSHIFT", "RSHIFT"]
+      .iter()
+      .for_each(|opstr| {
+        if s.contains(opstr) {
+          op = match *opstr {
+            "NOT" => Not,
+            "AND" => And,
+            "OR" => Or,
+            "LSHIFT" => Lshift,
+            "RSHIFT" => Rshift,
+            _ => Assign,
+          };
+        }
+      });
+    Ok(op)
+  }
+}
+
+impl Op {
+  fn name(&self) -> &'static str {
+    match self {
+      Assign => "",
+      And => "AND",
+      Or => "OR",
+      Not => "NOT",
+      Lshift => "LSHIFT",
+      Rshift => "RSHIFT",
+    }
+  }
+}
+
+fn is_numeric(s: &str) -> bool {
+  if s.is_empty() {
+    return false;
+  }
+  s.chars().all(|c| c.is_numeric())
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum Either<T, U> {
+  Left(T),
+  Right(U),
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Instruction {
+  op: Op,
+  left: Either<u16, String>,
+  right: Either<u16, String>,
+  output: String,
+}
+
+impl FromStr for Instruction {
+  type Err = ParseError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    fn get_input_output(s: &str) -> (&str, String) {
+      let split: Vec<&str> = s.split("->").map(|s| s.trim()).collect();
+      (split[0], split[1].to_string())
+    }
+
+    fn parse_arg(s: &str) -> Either<u16, String> {
+      if is_numeric(s) {
+        Either::Left(
+          s.parse::<u16>()
+            .unwrap_or_else(|_| panic!("error parsing {}", s)),
+        )
+      } else {
+        Either::Right(s.to_string())
+      }
+    }
+
+    fn get_input_args(op: &Op, s: &str) -> (Either<u16, String>, Either<u16, String>) {
+      let args: Vec<String> = s.split_whitespace().map(|s| s.trim().to_string()).collect();
+      let a1 = args[0].to_string();
+      let a2 = if args.len() > 1 {
+        args[1].to_string()
+      } else {
+        "".to_string()
+      };
+      let a3 = if args.len() > 2 {
+        args[2].to_string()
+      } else {
+        "".to_string()
+      };
+      let args: Vec<Either<u16, String>> =
+        [a1, a2, a3].iter().map(|a| parse_arg(a.trim())).collect();
+      let (a1, a2, a3) = (args[0].to_owned(), args[1].to_owned(), args[2].to_owned());
+      match op {
+        Assign => (a1, a2),
+        And => (a1, a3),
+        Or => (a1, a3),
+        Not => (a2, a3),
+        Lshift => (a1, a3),
+        Rshift => (a1, a3),
+      }
+    }
+    let (input, output) = get_input_output(s);
+
+    let op = Op::from_str(input).unwrap();
+    let (left, right) = get_input_args(&op, input);
+
+    Ok(Instruction {
+      op,
+      left,
+      right,
+      output,
+    })
+  }
+}
+
+fn parse_input(input: &str) -> Vec<Instruction> {
+  input
+    .lines()
+    .map(|line| Instruction::from_str(line).unwrap())
+    .collect()
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum CircuitError {
+  /// Wires that feed back into themselves, so they never settle.
+  Cycle(Vec<String>),
+  /// A wire read by a gate that nothing drives.
+  Undriven(String),
+  /// A wire driven by more than one gate.
+  DrivenTwice(String),
+  /// A wire that is not in the circuit at all.
+  UnknownWire(String),
+}
+
+impl Display for CircuitError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      CircuitError::Cycle(wires) => write!(f, "wires {} form a cycle", wires.join(", ")),
+      CircuitError::Undriven(wire) => write!(f, "nothing drives wire {}", wire),
+      CircuitError::DrivenTwice(wire) => write!(f, "wire {} is driven twice", wire),
+      CircuitError::UnknownWire(wire) => write!(f, "there is no wire {}", wire),
+    }
+  }
+}
+
+impl std::error::Error for CircuitError {}
+
+/// The wires read by `instruction`.
+fn inputs(instruction: &Instruction) -> impl Iterator<Item = &str> {
+  std::iter::once(&instruction.left)
+    .chain(std::iter::once(&instruction.right))
+    .filter_map(|arg| match arg {
+      Either::Right(wire) if !wire.is_empty() => Some(wire.as_str()),
+      _ => None,
+    })
+}
+
+/// A wired up set of instructions with every wire evaluated. Wires can be overridden with a
+/// fixed signal, after which only the wires downstream of it are evaluated again.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+  gates: HashMap<String, Instruction>,
+  /// Position of every wire in an order that puts each after the wires it reads.
+  rank: HashMap<String, usize>,
+  /// An edge from every wire to each gate output reading it.
+  graph: Digraph<()>,
+  overrides: HashMap<String, u16>,
+  values: HashMap<String, u16>,
+}
+
+impl Circuit {
+  pub fn new(instructions: &[Instruction]) -> Result<Circuit, CircuitError> {
+    let mut gates: HashMap<String, Instruction> = HashMap::new();
+    for instruction in instructions {
+      if gates
+        .insert(instruction.output.clone(), instruction.clone())
+        .is_some()
+      {
+        return Err(CircuitError::DrivenTwice(instruction.output.clone()));
+      }
+    }
+
+    let mut graph: Digraph<()> = Digraph::new();
+    for (wire, gate) in &gates {
+      graph.add_node(wire);
+      for input in inputs(gate) {
+        if !gates.contains_key(input) {
+          return Err(CircuitError::Undriven(input.to_string()));
+        }
+        graph.add_edge(input, wire, ());
+      }
+    }
+    let order: Vec<String> = graph
+      .topological_order()
+      .map_err(CircuitError::Cycle)?
+      .into_iter()
+      .map(String::from)
+      .collect();
+
+    let rank = order
+      .iter()
+      .enumerate()
+      .map(|(i, wire)| (wire.clone(), i))
+      .collect();
+    let mut circuit = Circuit {
+      gates,
+      rank,
+      graph,
+      overrides: HashMap::new(),
+      values: HashMap::new(),
+    };
+    circuit.evaluate(&order);
+    Ok(circuit)
+  }
+
+  fn arg(&self, arg: &Either<u16, String>) -> u16 {
+    match arg {
+      Either::Left(value) => *value,
+      Either::Right(wire) if wire.is_empty() => 0,
+      Either::Right(wire) => self.values[wire],
+    }
+  }
+
+  /// Evaluates `wires`, which must be in topological order.
+  fn evaluate(&mut self, wires: &[String]) {
+    for wire in wires {
+      let value = match self.overrides.get(wire) {
+        Some(value) => *value,
+        None => {
+          let gate = &self.gates[wire];
+          let left = self.arg(&gate.left);
+          let right = self.arg(&gate.right);
+          match gate.op {
+            Assign => left,
+            And => left & right,
+            Or => left | right,
+            Not => !left,
+            Lshift => left << right,
+            Rshift => left >> right,
+          }
+        }
+      };
+      self.values.insert(wire.clone(), value);
+    }
+  }
+
+  pub fn get(&self, wire: &str) -> Option<u16> {
+    self.values.get(wire).copied()
+  }
+
+  /// The signal on every wire.
+  pub fn values(&self) -> &HashMap<String, u16> {
+    &self.values
+  }
+
+  /// Drives `wire` with `value` instead of its gate and re-evaluates everything downstream of
+  /// it. Returns the number of wires evaluated.
+  pub fn set(&mut self, wire: &str, value: u16) -> Result<usize, CircuitError> {
+    if !self.gates.contains_key(wire) {
+      return Err(CircuitError::UnknownWire(wire.to_string()));
+    }
+    self.overrides.insert(wire.to_string(), value);
+    let mut affected: Vec<String> = self.graph.bfs(wire).into_iter().map(String::from).collect();
+    affected.sort_by_key(|wire| self.rank[wire]);
+    self.evaluate(&affected);
+    Ok(affected.len())
+  }
+}
+
+/// Wires as ellipses labelled with their signal when the circuit settles, and gates as boxes
+/// labelled with the op and any constant operand. A wire assigned straight from another wire
+/// gets no gate.
+fn to_dot(instructions: &[Instruction]) -> Graph {
+  let values = Circuit::new(instructions).ok().map(|c| c.values().clone());
+  let mut graph = Graph::new("circuit");
+  for instruction in instructions {
+    let wire = &instruction.output;
+    let label = match values.as_ref().and_then(|v| v.get(wire)) {
+      Some(value) => format!("{}\\n{}", wire, value),
+      None => wire.to_string(),
+    };
+    graph.node(wire, &[("label", &label)]);
+    let wires: Vec<&str> = inputs(instruction).collect();
+    if let (Assign, [input]) = (instruction.op, wires.as_slice()) {
+      graph.edge(input, wire, &[]);
+      continue;
+    }
+    let constants: Vec<String> = [&instruction.left, &instruction.right]
+      .iter()
+      .filter_map(|arg| match arg {
+        Either::Left(value) => Some(value.to_string()),
+        Either::Right(_) => None,
+      })
+      .collect();
+    let gate = format!("{} gate", wire);
+    let label = std::iter::once(instruction.op.name().to_string())
+      .chain(constants)
+      .filter(|s| !s.is_empty())
+      .collect::<Vec<String>>()
+      .join(" ");
+    graph.node(&gate, &[("label", &label), ("shape", "box")]);
+    for input in wires {
+      graph.edge(input, &gate, &[]);
+    }
+    graph.edge(&gate, wire, &[]);
+  }
+  graph.highlight_paths_to("a");
+  graph
+}
+
+fn circuit(instructions: &[Instruction]) -> Circuit {
+  Circuit::new(instructions).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn part_one(instructions: &[Instruction]) -> u16 {
+  circuit(instructions).get("a").expect("no wire a")
+}
+
+fn part_two(instructions: &[Instruction]) -> u16 {
+  let mut circuit = circuit(instructions);
+  let a = circuit.get("a").expect("no wire a");
+  circuit.set("b", a).unwrap();
+  circuit.get("a").unwrap()
+}
+
+impl Solution for Day07 {
+  type Input = Vec<Instruction>;
+  type PartOne = u16;
+  type PartTwo = u16;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    7
+  }
+
+  fn parse(&self, input: &str) -> Vec<Instruction> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<Instruction>) -> u16 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<Instruction>) -> u16 {
+    part_two(input)
+  }
+
+  fn dot(&self, input: &Vec<Instruction>) -> Option<Graph> {
+    Some(to_dot(input))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_parse_op() {
+    assert_eq!(Op::from_str("123").unwrap(), Op::Assign);
+  }
+
+  #[test]
+  fn test_parse_input() {
+    assert_eq!(
+      parse_input("456 -> y"),
+      vec![Instruction {
+        op: Op::Assign,
+        left: Either::Left(456),
+        right: Either::Right("".to_string()),
+        output: "y".to_string(),
+      }]
+    );
+    assert_eq!(
+      parse_input("NOT 456 -> y"),
+      vec![Instruction {
+        op: Op::Not,
+        left: Either::Left(456),
+        right: Either::Right("".to_string()),
+        output: "y".to_string(),
+      }]
+    );
+
+    assert_eq!(
+      parse_input(
+        "123 -> a
+456 -> y
+a AND y -> d
+a OR y -> e
+a LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT a -> h
+NOT y -> i"
+      ),
+      vec![
+        Instruction {
+          op: Assign,
+          left: Either::Left(123),
+          right: Either::Right("".to_string()),
+          output: "a".to_string()
+        },
+        Instruction {
+          op: Assign,
+          left: Either::Left(456),
+          right: Either::Right("".to_string()),
+          output: "y".to_string()
+        },
+        Instruction {
+          op: And,
+          left: Either::Right("a".to_string()),
+          right: Either::Right("y".to_string()),
+          output: "d".to_string()
+        },
+        Instruction {
+          op: Or,
+          left: Either::Right("a".to_string()),
+          right: Either::Right("y".to_string()),
+          output: "e".to_string()
+        },
+        Instruction {
+          op: Lshift,
+          left: Either::Right("a".to_string()),
+          right: Either::Left(2),
+          output: "f".to_string()
+        },
+        Instruction {
+          op: Rshift,
+          left: Either::Right("y".to_string()),
+          right: Either::Left(2),
+          output: "g".to_string()
+        },
+        Instruction {
+          op: Not,
+          left: Either::Right("a".to_string()),
+          right: Either::Right("".to_string()),
+          output: "h".to_string()
+        },
+        Instruction {
+          op: Not,
+          left: Either::Right("y".to_string()),
+          right: Either::Right("".to_string()),
+          output: "i".to_string()
+        },
+      ]
+    );
+    assert_eq!(parse_input(&Day07.input()).len(), 339);
+  }
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(
+      part_one(&parse_input(
+        "123 -> a
+456 -> y
+a AND y -> d
+a OR y -> e
+a LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT a -> h
+NOT y -> i"
+      )),
+      123
+    );
+    assert_eq!(part_one(&parse_input(&Day07.input())), 16076);
+  }
+
+  #[test]
+  fn test_circuit() {
+    let mut circuit = circuit(&parse_input(
+      "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i",
+    ));
+    let expected = [
+      ("d", 72),
+      ("e", 507),
+      ("f", 492),
+      ("g", 114),
+      ("h", 65412),
+      ("i", 65079),
+      ("x", 123),
+      ("y", 456),
+    ];
+    assert_eq!(circuit.values().len(), expected.len());
+    for (wire, value) in expected.iter() {
+      assert_eq!(circuit.get(wire), Some(*value), "wire {}", wire);
+    }
+    assert_eq!(circuit.set("y", 0), Ok(5));
+    assert_eq!(circuit.get("d"), Some(0));
+    assert_eq!(circuit.get("i"), Some(65535));
+    assert_eq!(circuit.get("h"), Some(65412));
+    assert_eq!(
+      circuit.set("z", 0),
+      Err(CircuitError::UnknownWire("z".to_string()))
+    );
+  }
+
+  #[test]
+  fn test_to_dot() {
+    let dot = to_dot(&parse_input(
+      "123 -> x\nx -> y\nx LSHIFT 2 -> a\nNOT y -> i",
+    ))
+    .to_string();
+    assert!(dot.contains("\"x gate\" [label=\"123\", shape=\"box\""));
+    assert!(dot.contains("\"x\" -> \"y\";"));
+    assert!(dot.contains("\"a gate\" [label=\"LSHIFT 2\", shape=\"box\", color=\"red\""));
+    assert!(dot.contains("\"a\" [label=\"a\\n492\", color=\"red\""));
+    assert!(dot.contains("\"y\" -> \"i gate\";"));
+  }
+
+  #[test]
+  fn test_circuit_errors() {
+    assert_eq!(
+      Circuit::new(&parse_input("a -> b\nb AND 1 -> a\n1 -> c")).unwrap_err(),
+      CircuitError::Cycle(vec!["a".to_string(), "b".to_string()])
+    );
+    assert_eq!(
+      Circuit::new(&parse_input("x -> a")).unwrap_err(),
+      CircuitError::Undriven("x".to_string())
+    );
+    assert_eq!(
+      Circuit::new(&parse_input("1 -> a\n2 -> a")).unwrap_err(),
+      CircuitError::DrivenTwice("a".to_string())
+    );
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(&Day07.input())), 2797);
+  }
+}
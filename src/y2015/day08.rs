@@ -1,6 +1,6 @@
-use std::time::Instant;
+use crate::solution::Solution;
 
-use common::io;
+pub struct Day08;
 
 fn unescape(s: &str) -> String {
   let mut out = String::new();
@@ -9,7 +9,7 @@ fn unescape(s: &str) -> String {
 
   let open_quote = iter.next().unwrap();
   if open_quote != '"' {
-    panic!(format!("String doesnt start with \": '{}'", s));
+    panic!("String doesnt start with \": '{}'", s);
   }
 
   while let Some(c) = iter.next() {
@@ -24,7 +24,7 @@ fn unescape(s: &str) -> String {
           let hex = u8::from_str_radix(&format!("{}{}", a, b,), 16).unwrap();
           out.push(hex as char);
         }
-        _ => panic!(format!("unknown escape code: '{}'", et)),
+        _ => panic!("unknown escape code: '{}'", et),
       }
     } else {
       out.push(c);
@@ -33,7 +33,7 @@ fn unescape(s: &str) -> String {
 
   if let Some(c) = out.pop() {
     if c != QUOTE {
-      panic!(format!("String doesnt end with \": '{}'", s));
+      panic!("String doesnt end with \": '{}'", s);
     }
   }
   out
@@ -64,7 +64,7 @@ fn escape(s: &str) -> String {
   }
 
   out.push(ESCAPED_QUOTE);
-  return out;
+  out
 }
 
 fn count_code_chars(s: &str) -> usize {
@@ -73,51 +73,58 @@ fn count_code_chars(s: &str) -> usize {
 
 fn count_data_chars(s: &str) -> usize {
   let unescaped = unescape(s);
-  return unescaped.chars().count();
+  unescaped.chars().count()
 }
 
 fn count_encoded_chars(s: &str) -> usize {
   let escaped = escape(s);
-  return escaped.chars().count();
+  escaped.chars().count()
+}
+
+fn parse_input(input: &str) -> Vec<String> {
+  input.lines().map(|line| line.to_string()).collect()
 }
 
-fn part_one(input: &str) -> u32 {
-  let parsed: Vec<&str> = input.lines().collect();
+fn part_one(parsed: &[String]) -> u32 {
   let code_counts: Vec<usize> = parsed.iter().map(|s| count_code_chars(s)).collect();
   let data_counts: Vec<usize> = parsed.iter().map(|s| count_data_chars(s)).collect();
   let sum_code: u32 = code_counts.iter().sum::<usize>() as u32;
   let sum_data: u32 = data_counts.iter().sum::<usize>() as u32;
-  return sum_code - sum_data;
+  sum_code - sum_data
 }
 
-fn part_two(input: &str) -> u32 {
-  let parsed: Vec<&str> = input.lines().collect();
+fn part_two(parsed: &[String]) -> u32 {
   let code_counts: Vec<usize> = parsed.iter().map(|s| count_code_chars(s)).collect();
   let encoded_counts: Vec<usize> = parsed.iter().map(|s| count_encoded_chars(s)).collect();
   let sum_code: u32 = code_counts.iter().sum::<usize>() as u32;
   let sum_encoded: u32 = encoded_counts.iter().sum::<usize>() as u32;
-  return sum_encoded - sum_code;
+  sum_encoded - sum_code
 }
 
-fn read_input() -> String {
-  return io::read_input("2015-08");
-}
+impl Solution for Day08 {
+  type Input = Vec<String>;
+  type PartOne = u32;
+  type PartTwo = u32;
 
-fn main() {
-  let input = read_input();
-  let p1_timer = Instant::now();
-  println!(
-    "part one {} {}ms",
-    part_one(&input),
-    p1_timer.elapsed().as_millis()
-  );
-  let p2_timer = Instant::now();
-  println!(
-    "part two {} {}ms",
-    part_two(&input),
-    p2_timer.elapsed().as_millis()
-  );
-  println!("total {}ms", p1_timer.elapsed().as_millis())
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    8
+  }
+
+  fn parse(&self, input: &str) -> Vec<String> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<String>) -> u32 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<String>) -> u32 {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -150,11 +157,11 @@ mod test {
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(&read_input()), 1350);
+    assert_eq!(part_one(&parse_input(&Day08.input())), 1350);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(&read_input()), 2085);
+    assert_eq!(part_two(&parse_input(&Day08.input())), 2085);
   }
 }
@@ -0,0 +1,187 @@
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::graph::{hamiltonian, Digraph, Objective, Tour};
+use crate::solution::Solution;
+
+pub struct Day09;
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Hash)]
+struct Location {
+  name: String,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Route {
+  a: Location,
+  b: Location,
+  length: usize,
+}
+
+impl FromStr for Route {
+  type Err = ();
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    lazy_static! {
+      static ref RE: Regex = Regex::new(r"^(\w+) to (\w+) = (\d+)$").unwrap();
+    }
+    let loc = RE
+      .captures(s)
+      .map(|cap| Route {
+        a: Location {
+          name: cap[1].to_string(),
+        },
+        b: Location {
+          name: cap[2].to_string(),
+        },
+        length: cap[3].parse::<usize>().unwrap(),
+      })
+      .unwrap();
+    Ok(loc)
+  }
+}
+
+fn parse_input(input: &str) -> Vec<Route> {
+  input
+    .lines()
+    .map(|line| Route::from_str(line).unwrap())
+    .collect()
+}
+
+/// The best route through every location, as location names and total distance.
+fn plan(routes: &[Route], objective: Objective) -> (Vec<String>, usize) {
+  let graph = Digraph::from_edges(routes.iter().flat_map(|route| {
+    vec![
+      (&route.a.name, &route.b.name, route.length),
+      (&route.b.name, &route.a.name, route.length),
+    ]
+  }));
+  let (names, weights) = graph.weight_matrix(|&length| length as i64);
+  let path = hamiltonian(&weights, Tour::Open, objective).expect("no route visits every location");
+  let order = path.order.iter().map(|&i| names[i].to_string()).collect();
+  (order, path.cost as usize)
+}
+
+fn part_one(routes: &[Route]) -> usize {
+  plan(routes, Objective::Min).1
+}
+
+fn part_two(routes: &[Route]) -> usize {
+  plan(routes, Objective::Max).1
+}
+
+impl Solution for Day09 {
+  type Input = Vec<Route>;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    9
+  }
+
+  fn parse(&self, input: &str) -> Vec<Route> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<Route>) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<Route>) -> usize {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TEST_INPUT: &str = "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
+
+  #[test]
+  fn test_route_from_str() {
+    assert_eq!(
+      Route::from_str("London to Dublin = 464").unwrap(),
+      Route {
+        a: Location {
+          name: "London".to_string()
+        },
+        b: Location {
+          name: "Dublin".to_string()
+        },
+        length: 464,
+      }
+    )
+  }
+
+  #[test]
+  fn test_parse_input() {
+    assert_eq!(
+      parse_input(TEST_INPUT),
+      vec![
+        Route {
+          a: Location {
+            name: "London".to_string()
+          },
+          b: Location {
+            name: "Dublin".to_string()
+          },
+          length: 464,
+        },
+        Route {
+          a: Location {
+            name: "London".to_string()
+          },
+          b: Location {
+            name: "Belfast".to_string()
+          },
+          length: 518,
+        },
+        Route {
+          a: Location {
+            name: "Dublin".to_string()
+          },
+          b: Location {
+            name: "Belfast".to_string()
+          },
+          length: 141,
+        },
+      ]
+    )
+  }
+
+  #[test]
+  fn test_plan() {
+    let routes = parse_input(TEST_INPUT);
+    let (order, distance) = plan(&routes, Objective::Min);
+    assert_eq!(distance, 605);
+    assert!(
+      order == vec!["London", "Dublin", "Belfast"] || order == vec!["Belfast", "Dublin", "London"]
+    );
+    let (order, distance) = plan(&routes, Objective::Max);
+    assert_eq!(distance, 982);
+    assert!(
+      order == vec!["Dublin", "London", "Belfast"] || order == vec!["Belfast", "London", "Dublin"]
+    );
+  }
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 605);
+    assert_eq!(part_one(&parse_input(&Day09.input())), 207);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 982);
+    assert_eq!(part_two(&parse_input(&Day09.input())), 804);
+  }
+}
@@ -1,17 +1,12 @@
-#![allow(unused_variables)]
-
-use std::time::Instant;
-
-use common::io;
 use std::char;
 
-fn read_input() -> String {
-  return io::read_input("2015-10");
-}
+use crate::solution::Solution;
+
+pub struct Day10;
 
 fn look_and_say(s: &str) -> String {
   let mut new = String::new();
-  let mut current = s.chars().nth(0).unwrap();
+  let mut current = s.chars().next().unwrap();
   let mut count = 0u32;
   for c in s.chars() {
     if c != current {
@@ -29,40 +24,49 @@ fn look_and_say(s: &str) -> String {
 
 fn look_and_say_repeat(s: &str, n: u32) -> usize {
   let mut new = s.to_owned();
-  for i in 0..n {
+  for _ in 0..n {
     let tmp = look_and_say(&new);
     new = tmp;
   }
   new.len()
 }
 
-fn parse_input(input: &str) -> &str {
-  input.lines().nth(0).unwrap()
+fn parse_input(input: &str) -> String {
+  input.lines().next().unwrap().to_string()
 }
 
 fn part_one(input: &str) -> usize {
-  look_and_say_repeat(parse_input(&read_input()), 40)
+  look_and_say_repeat(input, 40)
 }
 
 fn part_two(input: &str) -> usize {
-  look_and_say_repeat(parse_input(&read_input()), 50)
+  look_and_say_repeat(input, 50)
 }
 
-fn main() {
-  let input = read_input();
-  let p1_timer = Instant::now();
-  println!(
-    "part one {} {}ms",
-    part_one(&input),
-    p1_timer.elapsed().as_millis()
-  );
-  let p2_timer = Instant::now();
-  println!(
-    "part two {} {}ms",
-    part_two(&input),
-    p2_timer.elapsed().as_millis()
-  );
-  println!("total {}ms", p1_timer.elapsed().as_millis())
+impl Solution for Day10 {
+  type Input = String;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    10
+  }
+
+  fn parse(&self, input: &str) -> String {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &String) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &String) -> usize {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -79,11 +83,11 @@ mod test {
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(&read_input()), 492982);
+    assert_eq!(part_one(&parse_input(&Day10.input())), 492982);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(&read_input()), 6989950);
+    assert_eq!(part_two(&parse_input(&Day10.input())), 6989950);
   }
 }
@@ -1,19 +1,15 @@
 use std::char;
-use std::time::Instant;
 
-use common::io;
+use crate::solution::Solution;
+
+pub struct Day11;
 
 fn contains_iol(string: &str) -> bool {
-  for c in "iol".chars() {
-    if string.contains(c) {
-      return true;
-    }
-  }
-  return false;
+  "iol".chars().any(|c| string.contains(c))
 }
 
 fn is_xx(xx: &str) -> bool {
-  return xx.len() == 2 && xx.chars().nth(0) == xx.chars().nth(1);
+  xx.len() == 2 && xx.chars().next() == xx.chars().nth(1)
 }
 
 fn is_abc(xyz: &str) -> bool {
@@ -24,15 +20,11 @@ fn is_abc(xyz: &str) -> bool {
   let c1 = chars[0] as i32;
   let c2 = chars[1] as i32;
   let c3 = chars[2] as i32;
-  return c3 - c2 == 1 && c2 - c1 == 1;
-}
-
-fn read_input() -> String {
-  return io::read_input("2015-11");
+  c3 - c2 == 1 && c2 - c1 == 1
 }
 
 fn parse_input(input: &str) -> String {
-  input.lines().nth(0).unwrap().to_string()
+  input.lines().next().unwrap().to_string()
 }
 
 fn inc_char(c: char) -> char {
@@ -53,34 +45,33 @@ fn increment_str(s: &str) -> String {
       break;
     }
   }
-  let s: String = chars.into_iter().collect();
-  return s;
+  chars.into_iter().collect()
 }
 
 fn has_abc(s: &str) -> bool {
   for w in s.chars().collect::<Vec<char>>().windows(3) {
-    let s: String = w.into_iter().collect();
+    let s: String = w.iter().collect();
     if is_abc(&s) {
       return true;
     }
   }
-  return false;
+  false
 }
 
 fn has_xx(s: &str) -> bool {
   let mut first: (usize, String) = (0, String::new());
   for w in s.chars().collect::<Vec<char>>().windows(2).enumerate() {
     let i: usize = w.0;
-    let s: String = w.1.into_iter().collect();
+    let s: String = w.1.iter().collect();
     if is_xx(&s) {
-      if first.1 == "" {
+      if first.1.is_empty() {
         first.1 = s;
       } else if i - first.0 > 1 && s != first.1 {
         return true;
       }
     }
   }
-  return false;
+  false
 }
 
 fn next_password(current: &str) -> String {
@@ -88,7 +79,7 @@ fn next_password(current: &str) -> String {
   loop {
     new = increment_str(&new);
     if !contains_iol(&new) && has_xx(&new) && has_abc(&new) {
-      return new.to_owned();
+      return new;
     }
   }
 }
@@ -101,20 +92,30 @@ fn part_two(input: &str) -> String {
   next_password(&next_password(input))
 }
 
-fn main() {
-  let input = &parse_input(&read_input());
-  let timer = Instant::now();
-  println!(
-    "part one {} {}ms",
-    part_one(input),
-    timer.elapsed().as_millis()
-  );
-  println!(
-    "part two {} {}ms",
-    part_two(input),
-    timer.elapsed().as_millis()
-  );
-  println!("total {}ms", timer.elapsed().as_millis());
+impl Solution for Day11 {
+  type Input = String;
+  type PartOne = String;
+  type PartTwo = String;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    11
+  }
+
+  fn parse(&self, input: &str) -> String {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &String) -> String {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &String) -> String {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -123,7 +124,7 @@ mod test {
 
   #[test]
   fn test_parse_input() {
-    assert_eq!(parse_input(&read_input()), "hepxcrrq");
+    assert_eq!(parse_input(&Day11.input()), "hepxcrrq");
   }
 
   #[test]
@@ -176,11 +177,11 @@ mod test {
   fn test_part_one() {
     assert_eq!(part_one("abcdefgh"), "abcdffaa");
     assert_eq!(part_one("ghijklmn"), "ghjaabcc");
-    assert_eq!(part_one(&parse_input(&read_input())), "hepxxyzz");
+    assert_eq!(part_one(&parse_input(&Day11.input())), "hepxxyzz");
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(&parse_input(&read_input())), "heqaabcc");
+    assert_eq!(part_two(&parse_input(&Day11.input())), "heqaabcc");
   }
 }
@@ -1,24 +1,19 @@
-use std::time::Instant;
-
 use lazy_static::lazy_static;
 use regex::Regex;
-
-use common::io;
 use serde_json::Value;
 
+use crate::solution::Solution;
+
+pub struct Day12;
+
 lazy_static! {
   static ref RE: Regex = Regex::new(r"([-?\d+]+)").unwrap();
 }
 
-fn read_input() -> String {
-  return io::read_input("2015-12");
-}
-
 fn extract_numbers(json: &str) -> Vec<i32> {
-  return RE
-    .captures_iter(json)
+  RE.captures_iter(json)
     .map(|cap| cap[1].parse::<i32>().unwrap())
-    .collect();
+    .collect()
 }
 
 fn extract_numbers_no_red(json: &str) -> Vec<i32> {
@@ -45,35 +40,45 @@ fn extract_numbers_no_red(json: &str) -> Vec<i32> {
       }
     }
   }
-  return sum;
+  sum
+}
+
+fn parse_input(input: &str) -> String {
+  input.trim().to_string()
 }
 
-#[allow(unused_variables)]
 fn part_one(input: &str) -> i32 {
-  return extract_numbers(input).iter().sum();
+  extract_numbers(input).iter().sum()
 }
 
-#[allow(unused_variables)]
 fn part_two(input: &str) -> i32 {
-  return extract_numbers_no_red(input).iter().sum();
+  extract_numbers_no_red(input).iter().sum()
 }
 
-fn main() {
-  let input = read_input();
-
-  let p1_timer = Instant::now();
-  println!(
-    "part one {} {}ms",
-    part_one(&input),
-    p1_timer.elapsed().as_millis()
-  );
-  let p2_timer = Instant::now();
-  println!(
-    "part two {} {}ms",
-    part_two(&input),
-    p2_timer.elapsed().as_millis()
-  );
-  println!("total {}ms", p1_timer.elapsed().as_millis())
+impl Solution for Day12 {
+  type Input = String;
+  type PartOne = i32;
+  type PartTwo = i32;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    12
+  }
+
+  fn parse(&self, input: &str) -> String {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &String) -> i32 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &String) -> i32 {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -105,11 +110,11 @@ mod test {
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(&read_input()), 191164);
+    assert_eq!(part_one(&parse_input(&Day12.input())), 191164);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(&read_input()), 87842);
+    assert_eq!(part_two(&parse_input(&Day12.input())), 87842);
   }
 }
@@ -0,0 +1,136 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::graph::{Objective, Table};
+use crate::solution::Solution;
+
+pub struct Day13;
+
+lazy_static! {
+  static ref RE: Regex =
+    Regex::new(r"^(\w+) would (gain|lose) (\d+) happiness units by sitting next to (\w+).$")
+      .unwrap();
+}
+
+#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+struct Person {
+  name: String,
+}
+
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct HappinessRule {
+  person1: Person,
+  person2: Person,
+  gain: i32,
+}
+
+fn parse_input(input: &str) -> Vec<HappinessRule> {
+  input
+    .lines()
+    .map(|line| {
+      RE.captures(line)
+        .map(|c| {
+          let mut units = c[3].parse::<i32>().unwrap();
+          if &c[2] == "lose" {
+            units *= -1;
+          }
+          HappinessRule {
+            person1: Person {
+              name: c[1].to_string(),
+            },
+            person2: Person {
+              name: c[4].to_string(),
+            },
+            gain: units,
+          }
+        })
+        .unwrap()
+    })
+    .collect()
+}
+
+fn table(rules: &[HappinessRule]) -> Table {
+  let mut table = Table::new();
+  for rule in rules {
+    table.add(&rule.person1.name, &rule.person2.name, rule.gain as i64);
+  }
+  table
+}
+
+fn best(table: &Table) -> i64 {
+  table.best(Objective::Max).expect("nobody to seat").score
+}
+
+fn part_one(rules: &[HappinessRule]) -> i64 {
+  best(&table(rules))
+}
+
+fn part_two(rules: &[HappinessRule]) -> i64 {
+  let mut table = table(rules);
+  table.add_neutral_guest("kalvatn");
+  best(&table)
+}
+
+impl Solution for Day13 {
+  type Input = Vec<HappinessRule>;
+  type PartOne = i64;
+  type PartTwo = i64;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    13
+  }
+
+  fn parse(&self, input: &str) -> Vec<HappinessRule> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<HappinessRule>) -> i64 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<HappinessRule>) -> i64 {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TEST_INPUT: &str = "Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.";
+
+  #[test]
+  fn test_seating() {
+    let seating = table(&parse_input(TEST_INPUT))
+      .best(Objective::Max)
+      .unwrap();
+    assert_eq!(seating.score, 330);
+    assert_eq!(seating.order, vec!["Alice", "Bob", "Carol", "David"]);
+  }
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 330);
+    assert_eq!(part_one(&parse_input(&Day13.input())), 618);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(&Day13.input())), 601);
+  }
+}
@@ -1,9 +1,9 @@
-use std::time::Instant;
-
 use lazy_static::lazy_static;
 use regex::Regex;
 
-use common::io;
+use crate::solution::Solution;
+
+pub struct Day14;
 
 lazy_static! {
   static ref RE: Regex = Regex::new(
@@ -12,8 +12,9 @@ lazy_static! {
   .unwrap();
 }
 
-#[derive(Debug)]
-struct Reindeer {
+#[derive(Debug, Clone)]
+pub struct Reindeer {
+  #[allow(dead_code)]
   name: String,
   speed: usize,
   fly_secs: usize,
@@ -21,7 +22,7 @@ struct Reindeer {
   state: ReindeerState,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct ReindeerState {
   distance: usize,
   flying_for: usize,
@@ -46,10 +47,6 @@ impl Reindeer {
   }
 }
 
-fn read_input() -> String {
-  return io::read_input("2015-14");
-}
-
 fn parse_input(input: &str) -> Vec<Reindeer> {
   input
     .lines()
@@ -72,10 +69,9 @@ fn parse_input(input: &str) -> Vec<Reindeer> {
     .collect()
 }
 
-#[allow(unused_variables)]
-fn part_one(input: &str, secs: usize) -> usize {
-  let mut reindeers = parse_input(input);
-  for i in 1..secs + 1 {
+fn part_one(reindeers: &[Reindeer], secs: usize) -> usize {
+  let mut reindeers = reindeers.to_vec();
+  for _ in 0..secs {
     reindeers.iter_mut().for_each(|r| {
       r.fly();
     });
@@ -83,13 +79,12 @@ fn part_one(input: &str, secs: usize) -> usize {
   reindeers.iter().map(|r| r.state.distance).max().unwrap()
 }
 
-#[allow(unused_variables)]
-fn part_two(input: &str, secs: usize) -> usize {
-  let mut reindeers = parse_input(input);
-  for i in 1..secs + 1 {
+fn part_two(reindeers: &[Reindeer], secs: usize) -> usize {
+  let mut reindeers = reindeers.to_vec();
+  for _ in 0..secs {
     reindeers.iter_mut().for_each(|r| r.fly());
-    reindeers.sort_by(|a, b| b.state.distance.cmp(&a.state.distance));
-    let leading_distance = reindeers.iter().nth(0).unwrap().state.distance;
+    reindeers.sort_by_key(|r| std::cmp::Reverse(r.state.distance));
+    let leading_distance = reindeers[0].state.distance;
     reindeers.iter_mut().for_each(|r| {
       if r.state.distance == leading_distance {
         r.state.points += 1;
@@ -99,22 +94,30 @@ fn part_two(input: &str, secs: usize) -> usize {
   reindeers.iter().map(|r| r.state.points).max().unwrap()
 }
 
-fn main() {
-  let input = read_input();
-
-  let p1_timer = Instant::now();
-  println!(
-    "part one {} {}ms",
-    part_one(&input, 2503),
-    p1_timer.elapsed().as_millis()
-  );
-  let p2_timer = Instant::now();
-  println!(
-    "part two {} {}ms",
-    part_two(&input, 2503),
-    p2_timer.elapsed().as_millis()
-  );
-  println!("total {}ms", p1_timer.elapsed().as_millis())
+impl Solution for Day14 {
+  type Input = Vec<Reindeer>;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2015
+  }
+
+  fn day(&self) -> u8 {
+    14
+  }
+
+  fn parse(&self, input: &str) -> Vec<Reindeer> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<Reindeer>) -> usize {
+    part_one(input, 2503)
+  }
+
+  fn part_two(&self, input: &Vec<Reindeer>) -> usize {
+    part_two(input, 2503)
+  }
 }
 
 #[cfg(test)]
@@ -127,20 +130,20 @@ Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(TEST_INPUT, 10), 160);
-    assert_eq!(part_one(TEST_INPUT, 11), 176);
-    assert_eq!(part_one(TEST_INPUT, 12), 176);
-    assert_eq!(part_one(TEST_INPUT, 138), 176);
-    assert_eq!(part_one(TEST_INPUT, 139), 176);
-    assert_eq!(part_one(TEST_INPUT, 1000), 1120);
-    assert_eq!(part_one(&read_input(), 2503), 2640);
+    assert_eq!(part_one(&parse_input(TEST_INPUT), 10), 160);
+    assert_eq!(part_one(&parse_input(TEST_INPUT), 11), 176);
+    assert_eq!(part_one(&parse_input(TEST_INPUT), 12), 176);
+    assert_eq!(part_one(&parse_input(TEST_INPUT), 138), 176);
+    assert_eq!(part_one(&parse_input(TEST_INPUT), 139), 176);
+    assert_eq!(part_one(&parse_input(TEST_INPUT), 1000), 1120);
+    assert_eq!(part_one(&parse_input(&Day14.input()), 2503), 2640);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(TEST_INPUT, 1), 1);
-    assert_eq!(part_two(TEST_INPUT, 140), 139);
-    assert_eq!(part_two(TEST_INPUT, 1000), 689);
-    assert_eq!(part_two(&read_input(), 2503), 1102);
+    assert_eq!(part_two(&parse_input(TEST_INPUT), 1), 1);
+    assert_eq!(part_two(&parse_input(TEST_INPUT), 140), 139);
+    assert_eq!(part_two(&parse_input(TEST_INPUT), 1000), 689);
+    assert_eq!(part_two(&parse_input(&Day14.input()), 2503), 1102);
   }
 }
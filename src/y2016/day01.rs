@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use crate::geom::{Command, Heading, Point2};
+use crate::solution::Solution;
+
+pub struct Day01;
+
+fn parse_input(input: &str) -> Vec<Command> {
+  input.split(',').map(|s| s.parse().unwrap()).collect()
+}
+
+/// Each instruction turns first, then walks that many blocks.
+fn walk(instructions: &[Command]) -> impl Iterator<Item = Point2> + '_ {
+  let mut facing = Heading::North;
+  instructions.iter().flat_map(move |command| {
+    let (turn, blocks) = match command {
+      Command::Turn(turn, blocks) => (*turn, *blocks),
+      _ => panic!("impossiburu"),
+    };
+    facing = facing.turn(turn, 1);
+    std::iter::repeat_n(facing.delta(), blocks as usize)
+  })
+}
+
+fn part_one(instructions: &[Command]) -> i64 {
+  let pos: Point2 = walk(instructions).fold(Point2::origin(), |pos, step| pos + step);
+  pos.manhattan(&Point2::origin())
+}
+
+fn part_two(instructions: &[Command]) -> i64 {
+  let mut pos = Point2::origin();
+  let mut visited: HashSet<Point2> = HashSet::new();
+  visited.insert(pos);
+  for step in walk(instructions) {
+    pos += step;
+    if !visited.insert(pos) {
+      break;
+    }
+  }
+  pos.manhattan(&Point2::origin())
+}
+
+impl Solution for Day01 {
+  type Input = Vec<Command>;
+  type PartOne = i64;
+  type PartTwo = i64;
+
+  fn year(&self) -> u16 {
+    2016
+  }
+
+  fn day(&self) -> u8 {
+    1
+  }
+
+  fn parse(&self, input: &str) -> Vec<Command> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<Command>) -> i64 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<Command>) -> i64 {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input("R2, L3")), 5);
+    assert_eq!(part_one(&parse_input("R2, R2, R2")), 2);
+    assert_eq!(part_one(&parse_input("R5, L5, R5, R3")), 12);
+    assert_eq!(part_one(&parse_input(&Day01.input())), 271);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input("R8, R4, R4, R8")), 4);
+    assert_eq!(part_two(&parse_input(&Day01.input())), 153);
+  }
+}
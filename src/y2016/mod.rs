@@ -0,0 +1,7 @@
+use crate::solution::Registry;
+
+pub mod day01;
+
+pub fn register(registry: &mut Registry) {
+  registry.register(day01::Day01);
+}
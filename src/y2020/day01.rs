@@ -0,0 +1,78 @@
+use crate::solution::Solution;
+
+pub struct Day01;
+
+fn parse_input(input: &str) -> Vec<u32> {
+  input
+    .lines()
+    .map(|line| line.parse::<u32>().unwrap())
+    .collect()
+}
+
+fn part_one(numbers: &[u32]) -> u32 {
+  for i in numbers {
+    for j in numbers {
+      if j != i && j + i == 2020 {
+        return j * i;
+      }
+    }
+  }
+  panic!("impossiburu");
+}
+
+fn part_two(numbers: &[u32]) -> u32 {
+  for i in numbers {
+    for j in numbers {
+      if i + j > 2020 {
+        continue;
+      }
+      for k in numbers {
+        if j != i && j != k && j + i + k == 2020 {
+          return j * i * k;
+        }
+      }
+    }
+  }
+  panic!("impossiburu");
+}
+
+impl Solution for Day01 {
+  type Input = Vec<u32>;
+  type PartOne = u32;
+  type PartTwo = u32;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    1
+  }
+
+  fn parse(&self, input: &str) -> Vec<u32> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<u32>) -> u32 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<u32>) -> u32 {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(&Day01.input())), 32064);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(&Day01.input())), 193598720);
+  }
+}
@@ -1,13 +1,14 @@
 use std::str::FromStr;
 use std::string::ParseError;
-use std::time::{Duration, Instant};
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
-use common::io;
+use crate::solution::Solution;
 
-struct Policy {
+pub struct Day02;
+
+pub struct Policy {
   n1: usize,
   n2: usize,
   letter: char,
@@ -16,14 +17,14 @@ struct Policy {
 
 impl Policy {
   fn password_has_valid_count(&self) -> bool {
-    let count = &self.password.chars().filter(|c| c == &self.letter).count();
-    count >= &self.n1 && count <= &self.n2
+    let count = self.password.chars().filter(|c| c == &self.letter).count();
+    (self.n1..=self.n2).contains(&count)
   }
 
   fn password_has_valid_positions(&self) -> bool {
-    let first = &self.password.chars().nth(&self.n1 - 1).unwrap();
-    let second = &self.password.chars().nth(&self.n2 - 1).unwrap();
-    return first != second && (first == &self.letter || second == &self.letter);
+    let first = self.password.chars().nth(self.n1 - 1).unwrap();
+    let second = self.password.chars().nth(self.n2 - 1).unwrap();
+    first != second && (first == self.letter || second == self.letter)
   }
 }
 
@@ -47,10 +48,6 @@ impl FromStr for Policy {
   }
 }
 
-fn read_input() -> String {
-  return io::read_input("2020-02");
-}
-
 fn parse_input(input: &str) -> Vec<Policy> {
   input
     .lines()
@@ -58,37 +55,44 @@ fn parse_input(input: &str) -> Vec<Policy> {
     .collect()
 }
 
-fn part_one(input: &str) -> (usize, Duration) {
-  let vec = parse_input(input);
-  let timer = Instant::now();
-  (
-    vec
-      .iter()
-      .filter(|policy| policy.password_has_valid_count())
-      .count(),
-    timer.elapsed(),
-  )
+fn part_one(policies: &[Policy]) -> usize {
+  policies
+    .iter()
+    .filter(|policy| policy.password_has_valid_count())
+    .count()
 }
 
-fn part_two(input: &str) -> (usize, Duration) {
-  let vec = parse_input(input);
-  let timer = Instant::now();
-  (
-    vec
-      .iter()
-      .filter(|policy| policy.password_has_valid_positions())
-      .count(),
-    timer.elapsed(),
-  )
+fn part_two(policies: &[Policy]) -> usize {
+  policies
+    .iter()
+    .filter(|policy| policy.password_has_valid_positions())
+    .count()
 }
 
-fn main() {
-  let input = read_input();
+impl Solution for Day02 {
+  type Input = Vec<Policy>;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    2
+  }
+
+  fn parse(&self, input: &str) -> Vec<Policy> {
+    parse_input(input)
+  }
 
-  let p1 = part_one(&input);
-  println!("part two {:?} {:?}", p1.0, p1.1);
-  let p2 = part_two(&input);
-  println!("part two {:?} {:?}", p2.0, p2.1);
+  fn part_one(&self, input: &Vec<Policy>) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<Policy>) -> usize {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -117,13 +121,13 @@ mod test {
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(TEST_INPUT).0, 2);
-    assert_eq!(part_one(&read_input()).0, 500);
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 2);
+    assert_eq!(part_one(&parse_input(&Day02.input())), 500);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(TEST_INPUT).0, 1);
-    assert_eq!(part_two(&read_input()).0, 313);
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 1);
+    assert_eq!(part_two(&parse_input(&Day02.input())), 313);
   }
 }
@@ -0,0 +1,97 @@
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub struct Day03;
+
+fn parse_input(input: &str) -> Grid<bool> {
+  Grid::parse(input, |c| c == '#')
+}
+
+/// The map repeats to the right, so only the rows bound the descent.
+fn trees_encountered_for_slope(map: &Grid<bool>, slope: &(usize, usize)) -> u32 {
+  (0..map.height())
+    .step_by(slope.0)
+    .enumerate()
+    .filter(|&(step, y)| *map.get_wrapping((step * slope.1) as isize, y as isize))
+    .count() as u32
+}
+
+fn trees_encountered_for_slopes(map: &Grid<bool>, slopes: &[(usize, usize)]) -> u32 {
+  slopes
+    .iter()
+    .map(|slope| trees_encountered_for_slope(map, slope))
+    .product()
+}
+
+fn part_one(map: &Grid<bool>) -> u32 {
+  trees_encountered_for_slope(map, &(1, 3))
+}
+
+fn part_two(map: &Grid<bool>) -> u32 {
+  trees_encountered_for_slopes(map, &[(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)])
+}
+
+impl Solution for Day03 {
+  type Input = Grid<bool>;
+  type PartOne = u32;
+  type PartTwo = u32;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    3
+  }
+
+  fn parse(&self, input: &str) -> Grid<bool> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Grid<bool>) -> u32 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Grid<bool>) -> u32 {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TEST_INPUT: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+  #[test]
+  fn test_parse_input() {
+    let map = parse_input(TEST_INPUT);
+    assert!(map[(3, 0)]);
+    assert!(map[(0, 1)]);
+    assert!(map[(4, 1)]);
+    assert!(map[(10, 3)]);
+    assert!(!map[(0, 0)]);
+  }
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 7);
+    assert_eq!(part_one(&parse_input(&Day03.input())), 232);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 336);
+    assert_eq!(part_two(&parse_input(&Day03.input())), 3952291680);
+  }
+}
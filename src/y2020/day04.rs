@@ -1,30 +1,14 @@
-#![allow(unused_variables, unused_imports)]
+use crate::solution::Solution;
 
-use std::collections::{HashMap, HashSet};
-use std::time::Instant;
+pub struct Day04;
 
-use itertools::Itertools;
-use lazy_static::lazy_static;
-use regex::Regex;
-
-use common::io;
-
-fn read_input() -> String {
-  return io::read_input("2020-04");
-}
-
-fn parse_input(input: &str) -> Vec<&str> {
-  input.split("\n\n").collect()
+fn parse_input(input: &str) -> Vec<String> {
+  input.split("\n\n").map(|s| s.to_string()).collect()
 }
 
 fn has_required_fields(passport: &str) -> bool {
-  let required = vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
-  for n in &required {
-    if !passport.contains(n) {
-      return false;
-    }
-  }
-  return true;
+  let required = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+  required.iter().all(|n| passport.contains(n))
 }
 
 fn valid_pid(pid: &str) -> bool {
@@ -32,41 +16,40 @@ fn valid_pid(pid: &str) -> bool {
 }
 
 fn valid_hcl(hcl: &str) -> bool {
-  if hcl.starts_with("#") {
-    let c = hcl.trim_start_matches("#");
+  if let Some(c) = hcl.strip_prefix('#') {
     return c.len() == 6 && c.chars().all(|c| c.is_ascii_hexdigit());
   }
-  return false;
+  false
 }
 
 fn valid_ecl(ecl: &str) -> bool {
-  let valid = vec!["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+  let valid = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
   ecl.len() == 3 && valid.contains(&ecl)
 }
 fn valid_hgt(hgt: &str) -> bool {
-  if hgt.ends_with("in") {
-    let n = hgt.trim_end_matches("in").parse::<u8>().unwrap();
-    return n >= 59 && n <= 76;
-  } else if hgt.ends_with("cm") {
-    let n = hgt.trim_end_matches("cm").parse::<u8>().unwrap();
-    return n >= 150 && n <= 193;
-  }
-  return false;
+  if let Some(n) = hgt.strip_suffix("in") {
+    let n = n.parse::<u8>().unwrap();
+    return (59..=76).contains(&n);
+  } else if let Some(n) = hgt.strip_suffix("cm") {
+    let n = n.parse::<u8>().unwrap();
+    return (150..=193).contains(&n);
+  }
+  false
 }
 
 fn valid_byr(byr: &str) -> bool {
   let n = byr.parse::<u16>().unwrap();
-  n >= 1920 && n <= 2002
+  (1920..=2002).contains(&n)
 }
 
 fn valid_iyr(iyr: &str) -> bool {
   let n = iyr.parse::<u16>().unwrap();
-  n >= 2010 && n <= 2020
+  (2010..=2020).contains(&n)
 }
 
 fn valid_eyr(eyr: &str) -> bool {
   let n = eyr.parse::<u16>().unwrap();
-  n >= 2020 && n <= 2030
+  (2020..=2030).contains(&n)
 }
 
 fn is_valid(passport: &str) -> bool {
@@ -74,7 +57,7 @@ fn is_valid(passport: &str) -> bool {
     return false;
   }
   for entries in passport.split_whitespace() {
-    let split: Vec<&str> = entries.split(":").into_iter().collect();
+    let split: Vec<&str> = entries.split(':').collect();
     let field = split[0];
     let val = split[1];
     let valid = match field {
@@ -92,35 +75,47 @@ fn is_valid(passport: &str) -> bool {
       return false;
     }
   }
-  return true;
+  true
 }
 
-fn part_one(input: &str) -> usize {
-  parse_input(input)
+fn part_one(passports: &[String]) -> usize {
+  passports
     .iter()
     .filter(|passport| has_required_fields(passport))
     .count()
 }
 
-fn part_two(input: &str) -> usize {
-  parse_input(input)
+fn part_two(passports: &[String]) -> usize {
+  passports
     .iter()
     .filter(|passport| is_valid(passport))
     .count()
 }
 
-fn main() {
-  let input = read_input();
+impl Solution for Day04 {
+  type Input = Vec<String>;
+  type PartOne = usize;
+  type PartTwo = usize;
 
-  let time = Instant::now();
-  let p1 = part_one(&input);
-  let p1_time = time.elapsed();
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    4
+  }
+
+  fn parse(&self, input: &str) -> Vec<String> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<String>) -> usize {
+    part_one(input)
+  }
 
-  let time = Instant::now();
-  let p2 = part_two(&input);
-  let p2_time = time.elapsed();
-  println!("part one {:?} {:?}", p1, p1_time);
-  println!("part two {:?} {:?}", p2, p2_time);
+  fn part_two(&self, input: &Vec<String>) -> usize {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -209,14 +204,14 @@ hcl:#623a2f"
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(TEST_INPUT), 2);
-    assert_eq!(part_one(&read_input()), 219);
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 2);
+    assert_eq!(part_one(&parse_input(&Day04.input())), 219);
   }
 
   #[test]
   fn test_part_two() {
     assert_eq!(
-      part_two(
+      part_two(&parse_input(
         "eyr:1972 cid:100
 hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
 
@@ -230,11 +225,11 @@ ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
 hgt:59cm ecl:zzz
 eyr:2038 hcl:74454a iyr:2023
 pid:3556412378 byr:2007"
-      ),
+      )),
       0
     );
     assert_eq!(
-      part_two(
+      part_two(&parse_input(
         "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
 hcl:#623a2f
 
@@ -247,9 +242,9 @@ pid:545766238 ecl:hzl
 eyr:2022
 
 iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719"
-      ),
+      )),
       4
     );
-    assert_eq!(part_two(&read_input()), 127);
+    assert_eq!(part_two(&parse_input(&Day04.input())), 127);
   }
 }
@@ -1,10 +1,6 @@
-use std::time::Instant;
+use crate::solution::Solution;
 
-use common::io;
-
-fn read_input() -> String {
-  return io::read_input("2020-05");
-}
+pub struct Day05;
 
 fn get_seat(encoded: &str) -> (u16, u16) {
   let mut row: (u16, u16) = (0, 128);
@@ -24,7 +20,7 @@ fn get_seat(encoded: &str) -> (u16, u16) {
       }
     }
   }
-  return (row.0, col.0);
+  (row.0, col.0)
 }
 
 fn get_seat_ids(input: &str) -> Vec<u16> {
@@ -37,16 +33,15 @@ fn get_seat_ids(input: &str) -> Vec<u16> {
     .collect()
 }
 
-fn part_one(input: &str) -> u16 {
-  *get_seat_ids(input).iter().max().unwrap()
+fn part_one(seat_ids: &[u16]) -> u16 {
+  *seat_ids.iter().max().unwrap()
 }
 
-fn part_two(input: &str) -> u16 {
-  let mut seat_ids = get_seat_ids(input);
-  seat_ids.sort();
+fn part_two(seat_ids: &[u16]) -> u16 {
+  let mut seat_ids = seat_ids.to_vec();
+  seat_ids.sort_unstable();
   let mut prev = seat_ids[0];
-  for i in 1..seat_ids.len() {
-    let x = seat_ids[i];
+  for &x in seat_ids.iter().skip(1) {
     if (x - prev) > 1 {
       return prev + 1;
     }
@@ -55,18 +50,30 @@ fn part_two(input: &str) -> u16 {
   unreachable!("impossiburu")
 }
 
-fn main() {
-  let input = read_input();
+impl Solution for Day05 {
+  type Input = Vec<u16>;
+  type PartOne = u16;
+  type PartTwo = u16;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    5
+  }
 
-  let time = Instant::now();
-  let p1 = part_one(&input);
-  let p1_time = time.elapsed();
+  fn parse(&self, input: &str) -> Vec<u16> {
+    get_seat_ids(input)
+  }
 
-  let time = Instant::now();
-  let p2 = part_two(&input);
-  let p2_time = time.elapsed();
-  println!("part one {:?} {:?}", p1, p1_time);
-  println!("part two {:?} {:?}", p2, p2_time);
+  fn part_one(&self, input: &Vec<u16>) -> u16 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<u16>) -> u16 {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -91,11 +98,11 @@ mod test {
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(&read_input()), 835);
+    assert_eq!(part_one(&get_seat_ids(&Day05.input())), 835);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(&read_input()), 649);
+    assert_eq!(part_two(&get_seat_ids(&Day05.input())), 649);
   }
 }
@@ -1,14 +1,14 @@
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
-use common::io;
-use std::iter::FromIterator;
+use crate::solution::Solution;
 
-fn read_input() -> String {
-  return io::read_input("2020-06");
+pub struct Day06;
+
+fn parse_input(input: &str) -> Vec<String> {
+  input.split("\n\n").map(|group| group.to_string()).collect()
 }
 
-fn part_one(input: &str) -> usize {
+fn part_one(groups: &[String]) -> usize {
   // let mut count = 0;
   // let mut yes: HashSet<char> = HashSet::new();
   // for l in input.lines() {
@@ -22,14 +22,13 @@ fn part_one(input: &str) -> usize {
   // }
   // count += yes.len();
   // return count;
-  input.split("\n\n").fold(0, |acc, line| {
-    let map = line.lines().flat_map(|l| l.chars().into_iter());
-    let set = HashSet::<char>::from_iter(map);
+  groups.iter().fold(0, |acc, line| {
+    let set: HashSet<char> = line.lines().flat_map(|l| l.chars()).collect();
     acc + set.len()
   })
 }
 
-fn part_two(input: &str) -> usize {
+fn part_two(groups: &[String]) -> usize {
   // let mut count = 0;
   // let mut people = 0;
   // let mut yes_by_q: HashMap<char, usize> = HashMap::new();
@@ -48,7 +47,7 @@ fn part_two(input: &str) -> usize {
   // }
   // count += &yes_by_q.values().filter(|v| v == &&people).count();
   // return count;
-  input.split("\n\n").fold(0, |acc, group| {
+  groups.iter().fold(0, |acc, group| {
     let answers_per_person = group.lines();
     let people = group.lines().count();
     let all_answers = answers_per_person
@@ -56,24 +55,36 @@ fn part_two(input: &str) -> usize {
       .collect::<Vec<char>>();
     let mut map = HashMap::<char, usize>::new();
     for c in all_answers {
-      *map.entry(c).or_insert(0) += 1
+      *map.entry(c).or_default() += 1
     }
     acc + map.values().filter(|v| **v == people).count()
   })
 }
 
-fn main() {
-  let input = read_input();
+impl Solution for Day06 {
+  type Input = Vec<String>;
+  type PartOne = usize;
+  type PartTwo = usize;
 
-  let time = Instant::now();
-  let p1 = part_one(&input);
-  let p1_time = time.elapsed();
+  fn year(&self) -> u16 {
+    2020
+  }
 
-  let time = Instant::now();
-  let p2 = part_two(&input);
-  let p2_time = time.elapsed();
-  println!("part one {:?} {:?}", p1, p1_time);
-  println!("part two {:?} {:?}", p2, p2_time);
+  fn day(&self) -> u8 {
+    6
+  }
+
+  fn parse(&self, input: &str) -> Vec<String> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<String>) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<String>) -> usize {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -98,13 +109,13 @@ b";
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(TEST_INPUT), 11);
-    assert_eq!(part_one(&read_input()), 6457);
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 11);
+    assert_eq!(part_one(&parse_input(&Day06.input())), 6457);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(TEST_INPUT), 6);
-    assert_eq!(part_two(&read_input()), 3260);
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 6);
+    assert_eq!(part_two(&parse_input(&Day06.input())), 3260);
   }
 }
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::dot::Graph;
+use crate::graph::Digraph;
+use crate::solution::Solution;
+
+pub struct Day07;
+
+const TARGET: &str = "shiny gold";
+
+/// The bag rules as a graph with an edge from every colour to each colour it directly
+/// contains, weighted by how many.
+#[derive(Debug, Clone)]
+pub struct BagRules {
+  graph: Digraph<u64>,
+}
+
+impl FromStr for BagRules {
+  type Err = String;
+
+  fn from_str(input: &str) -> Result<BagRules, String> {
+    lazy_static! {
+      static ref RE_HAS_BAGS: Regex = Regex::new(r"^([\w+\s]+) bags contain").unwrap();
+      static ref RE_YES_BAGS: Regex = Regex::new(r"(\d+) ([\w+\s]+) bags?").unwrap();
+    }
+    let mut graph = Digraph::new();
+    for line in input.lines().filter(|l| !l.trim().is_empty()) {
+      let cap = RE_HAS_BAGS
+        .captures(line)
+        .ok_or_else(|| format!("invalid rule {:?}", line))?;
+      let colour = &cap[1];
+      graph.add_node(colour);
+      for l in line.split(',') {
+        if let Some(cap) = RE_YES_BAGS.captures(l.trim()) {
+          let count = cap[1]
+            .parse()
+            .map_err(|_| format!("invalid count in {:?}", line))?;
+          graph.add_edge(colour, &cap[2], count);
+        }
+      }
+    }
+    Ok(BagRules { graph })
+  }
+}
+
+impl BagRules {
+  /// The number of other colours that can end up holding a `colour` bag.
+  pub fn containers(&self, colour: &str) -> usize {
+    self.graph.ancestors(colour).len().saturating_sub(1)
+  }
+
+  /// How many bags every colour holds in total, folding each colour's contents once.
+  pub fn bags_inside(&self) -> HashMap<&str, u64> {
+    self
+      .graph
+      .fold_dag(|_, contents| {
+        contents
+          .iter()
+          .map(|&(_, &count, &inside)| count * (1 + inside))
+          .sum()
+      })
+      .unwrap_or_else(|cycle| panic!("bags {} contain themselves", cycle.join(", ")))
+  }
+
+  /// How many bags of each colour a `colour` bag ends up holding, counting through every level
+  /// of nesting. Copies are pushed down from `colour` in topological order, so every bag's
+  /// contents are expanded once however many of it there are. Empty for an unknown colour.
+  pub fn breakdown<'a>(&'a self, colour: &str) -> HashMap<&'a str, u64> {
+    let mut copies: HashMap<&str, u64> = HashMap::new();
+    if !self.graph.contains(colour) {
+      return copies;
+    }
+    for (inner, &count) in self.graph.successors(colour) {
+      *copies.entry(inner).or_insert(0) += count;
+    }
+    let order = self
+      .graph
+      .topological_order()
+      .unwrap_or_else(|cycle| panic!("bags {} contain themselves", cycle.join(", ")));
+    for bag in order {
+      let n = match copies.get(bag) {
+        Some(&n) => n,
+        None => continue,
+      };
+      for (inner, &count) in self.graph.successors(bag) {
+        *copies.entry(inner).or_insert(0) += n * count;
+      }
+    }
+    copies
+  }
+
+  /// The bags inside a `colour` bag, one colour per line with the most common first.
+  pub fn explain(&self, colour: &str) -> String {
+    let mut breakdown: Vec<(&str, u64)> = self.breakdown(colour).into_iter().collect();
+    breakdown.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    let total: u64 = breakdown.iter().map(|&(_, n)| n).sum();
+    let mut lines = vec![format!("{} bags inside {}", total, colour)];
+    lines.extend(breakdown.iter().map(|(c, n)| format!("{} {}", n, c)));
+    lines.join("\n")
+  }
+
+  /// One node per colour and a `contains` edge labelled with the count, highlighting every
+  /// bag that can end up holding a `target` one.
+  pub fn to_dot(&self, target: &str) -> Graph {
+    let mut graph = Graph::new("bags");
+    let mut colours: Vec<&str> = self.graph.nodes().collect();
+    colours.sort_unstable();
+    for colour in colours {
+      graph.node(colour, &[]);
+      // Successors come back newest edge first, so reverse them to write the edges in the
+      // order the rule lists them.
+      for (other, count) in self.graph.successors(colour).into_iter().rev() {
+        graph.edge(colour, other, &[("label", &count.to_string())]);
+      }
+    }
+    graph.highlight_paths_to(target);
+    graph
+  }
+}
+
+fn parse_input(input: &str) -> BagRules {
+  input.parse().unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn part_one(rules: &BagRules) -> usize {
+  rules.containers(TARGET)
+}
+
+fn part_two(rules: &BagRules) -> u64 {
+  rules.bags_inside()[TARGET]
+}
+
+impl Solution for Day07 {
+  type Input = BagRules;
+  type PartOne = usize;
+  type PartTwo = u64;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    7
+  }
+
+  fn parse(&self, input: &str) -> BagRules {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &BagRules) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &BagRules) -> u64 {
+    part_two(input)
+  }
+
+  fn dot(&self, input: &BagRules) -> Option<Graph> {
+    Some(input.to_dot(TARGET))
+  }
+
+  fn explain(&self, input: &BagRules) -> Option<String> {
+    Some(input.explain(TARGET))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TEST_INPUT: &str = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 4);
+    assert_eq!(part_one(&parse_input(&Day07.input())), 326);
+  }
+
+  #[test]
+  fn test_to_dot() {
+    let dot = parse_input(TEST_INPUT).to_dot(TARGET).to_string();
+    assert!(dot.contains("\"bright white\" -> \"shiny gold\" [label=\"1\", color=\"red\""));
+    assert!(dot.contains("\"light red\" [color=\"red\""));
+    assert!(dot.contains("\"shiny gold\" -> \"dark olive\" [label=\"1\"];"));
+    assert!(dot.contains("\"faded blue\";"));
+  }
+
+  #[test]
+  fn test_any_colour() {
+    let rules = parse_input(TEST_INPUT);
+    assert_eq!(rules.containers("faded blue"), 7);
+    assert_eq!(rules.containers("light red"), 0);
+    assert_eq!(rules.containers("black"), 0);
+    let inside = rules.bags_inside();
+    assert_eq!(inside["dark olive"], 7);
+    assert_eq!(inside["faded blue"], 0);
+    assert_eq!(inside["light red"], 186);
+  }
+
+  #[test]
+  fn test_breakdown() {
+    let rules = parse_input(TEST_INPUT);
+    let breakdown = rules.breakdown(TARGET);
+    let mut colours: Vec<(&str, u64)> = breakdown.into_iter().collect();
+    colours.sort_unstable();
+    assert_eq!(
+      colours,
+      vec![
+        ("dark olive", 1),
+        ("dotted black", 16),
+        ("faded blue", 13),
+        ("vibrant plum", 2)
+      ]
+    );
+    assert_eq!(
+      rules.explain(TARGET),
+      "32 bags inside shiny gold\n16 dotted black\n13 faded blue\n2 vibrant plum\n1 dark olive"
+    );
+    assert!(rules.breakdown("faded blue").is_empty());
+    let rules = parse_input(&Day07.input());
+    for (colour, inside) in rules.bags_inside() {
+      assert_eq!(
+        rules.breakdown(colour).values().sum::<u64>(),
+        inside,
+        "{}",
+        colour
+      );
+    }
+    assert!(rules.breakdown("black").is_empty());
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 32);
+    assert_eq!(part_two(&parse_input(&Day07.input())), 5635);
+  }
+}
@@ -0,0 +1,109 @@
+use crate::solution::Solution;
+use crate::vm::{Exit, Machine, Program};
+
+pub struct Day08;
+
+fn parse_input(input: &str) -> Program {
+  input.parse().unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn part_one(program: &Program) -> i64 {
+  let mut machine = Machine::new(program);
+  assert_eq!(machine.run(), Exit::Looped);
+  machine.acc()
+}
+
+fn part_two(program: &Program) -> i64 {
+  let (_, acc) = program
+    .repair()
+    .expect("no single flip makes the program halt");
+  acc
+}
+
+/// Which instruction the repair flips, for `aoc run --explain`.
+fn explain(program: &Program) -> String {
+  match program.repair() {
+    Some((patch, acc)) => format!(
+      "flipping instruction {} from `{}` to `{}` halts with acc {}",
+      patch.index, program.instructions[patch.index], patch.instruction, acc
+    ),
+    None => "no single flip makes the program halt".to_string(),
+  }
+}
+
+impl Solution for Day08 {
+  type Input = Program;
+  type PartOne = i64;
+  type PartTwo = i64;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    8
+  }
+
+  fn parse(&self, input: &str) -> Program {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Program) -> i64 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Program) -> i64 {
+    part_two(input)
+  }
+
+  fn explain(&self, input: &Program) -> Option<String> {
+    Some(explain(input))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TEST_INPUT: &str = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 5);
+    assert_eq!(part_one(&parse_input(&Day08.input())), 1317);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 8);
+    assert_eq!(part_two(&parse_input(&Day08.input())), 1033);
+  }
+
+  #[test]
+  fn test_explain() {
+    assert_eq!(
+      explain(&parse_input(TEST_INPUT)),
+      "flipping instruction 7 from `jmp -4` to `nop -4` halts with acc 8"
+    );
+    assert_eq!(
+      explain(&parse_input("jmp +0\njmp -1")),
+      "no single flip makes the program halt"
+    );
+  }
+
+  #[test]
+  fn test_repair_matches_brute_force() {
+    let program = parse_input(&Day08.input());
+    let (patch, acc) = program.repair().unwrap();
+    assert_eq!(program.find_patch(program.flips()), Some((patch, acc)));
+    assert_eq!(patch.index, 408);
+  }
+}
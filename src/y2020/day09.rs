@@ -0,0 +1,132 @@
+use itertools::Itertools;
+
+use crate::solution::Solution;
+
+pub struct Day09;
+
+fn parse_input(input: &str) -> Vec<u64> {
+  input
+    .lines()
+    .map(|line| line.parse::<u64>().unwrap())
+    .collect()
+}
+
+fn find_invalid(numbers: &[u64], length: usize) -> u64 {
+  for (i, preamble) in numbers.windows(length).enumerate() {
+    let next_number = &numbers[i + length];
+    if preamble
+      .iter()
+      .combinations_with_replacement(2)
+      .map(|c| *c.first().unwrap() + *c.last().unwrap())
+      .all(|s| s != *next_number)
+    {
+      return *next_number;
+    }
+  }
+  unreachable!("impossiburu")
+}
+
+fn part_one(numbers: &[u64]) -> u64 {
+  find_invalid(numbers, 25)
+}
+
+fn range_for_sum(numbers: &[u64], target: u64) -> Result<(usize, usize), &'static str> {
+  let size = numbers.len();
+  for i in 0..size {
+    let mut sum = 0;
+    let mut j = i;
+    while j < size && sum < target {
+      sum += numbers[j];
+      if sum == target {
+        return Ok((i, j));
+      }
+      j += 1;
+    }
+  }
+  Err("no range found")
+}
+
+fn part_two(numbers: &[u64], p1: u64) -> u64 {
+  let (start, end) = range_for_sum(numbers, p1).unwrap();
+  let slice = &numbers[start..end];
+  let min = *slice.iter().min().unwrap();
+  let max = *slice.iter().max().unwrap();
+  min + max
+}
+
+impl Solution for Day09 {
+  type Input = Vec<u64>;
+  type PartOne = u64;
+  type PartTwo = u64;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    9
+  }
+
+  fn parse(&self, input: &str) -> Vec<u64> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<u64>) -> u64 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<u64>) -> u64 {
+    part_two(input, part_one(input))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TEST_INPUT: &str = "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+
+  #[test]
+  fn test_find_invalid() {
+    assert_eq!(find_invalid(&parse_input(TEST_INPUT), 5), 127);
+  }
+
+  #[test]
+  fn test_range_for_sum() {
+    assert_eq!(range_for_sum(&[1, 2, 3, 4], 6), Ok((0, 2)));
+    assert_eq!(range_for_sum(&[1, 2, 3, 4], 3), Ok((0, 1)));
+    assert_eq!(range_for_sum(&[1, 2, 3, 4], 7), Ok((2, 3)));
+    assert_eq!(range_for_sum(&[1, 2, 3, 4], 10), Ok((0, 3)));
+    assert_eq!(range_for_sum(&[1, 2, 3, 4], 8), Err("no range found"));
+  }
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(&Day09.input())), 31161678);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(TEST_INPUT), 127), 62);
+    assert_eq!(part_two(&parse_input(&Day09.input()), 31161678), 5453868);
+  }
+}
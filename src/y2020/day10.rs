@@ -1,13 +1,10 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
 use itertools::Itertools;
 
-use common::io;
+use crate::solution::Solution;
 
-fn read_input() -> String {
-  return io::read_input("2020-10");
-}
+pub struct Day10;
 
 fn parse_input(input: &str) -> Vec<u8> {
   let mut numbers: Vec<u8> = input
@@ -17,17 +14,16 @@ fn parse_input(input: &str) -> Vec<u8> {
   let max = *numbers.iter().max().unwrap();
   numbers.push(0);
   numbers.push(max + 3);
-  numbers.sort();
-  return numbers;
+  numbers.sort_unstable();
+  numbers
 }
 
-fn find_joltage_diffs(input: &str) -> (u8, u8) {
-  let numbers = parse_input(input);
+fn find_joltage_diffs(numbers: &[u8]) -> (u8, u8) {
   let (mut one, mut three) = (0, 0);
   let mut rating = 0;
-  for i in 0..numbers.len() {
-    let adapter = numbers[i] as i32;
-    let diff = adapter as i32 - rating;
+  for &adapter in numbers {
+    let adapter = adapter as i32;
+    let diff = adapter - rating;
     if diff == 1 {
       one += 1;
     }
@@ -36,14 +32,14 @@ fn find_joltage_diffs(input: &str) -> (u8, u8) {
     }
     rating = adapter;
   }
-  return (one, three);
+  (one, three)
 }
 
-fn find_adapter_arrangements(numbers: &Vec<u8>) -> u64 {
+fn find_adapter_arrangements(numbers: &[u8]) -> u64 {
   let mut counts: HashMap<u8, u64> = HashMap::new();
   counts.insert(0, 1);
 
-  for n in numbers.into_iter().dropping(1) {
+  for n in numbers.iter().dropping(1) {
     let mut count = 0;
     for i in 1..=3 {
       if n >= &i {
@@ -59,18 +55,16 @@ fn find_adapter_arrangements(numbers: &Vec<u8>) -> u64 {
 fn find_adapter_arrangements_oom(input: &str) -> u64 {
   let mut numbers = parse_input(input);
   let max = *numbers.iter().max().unwrap();
-  let min = *numbers.iter().min().unwrap();
   numbers.push(0);
   numbers.push(max + 3);
-  numbers.sort();
+  numbers.sort_unstable();
   numbers.reverse();
 
   let mut queue: VecDeque<(usize, Vec<u8>)> = VecDeque::new();
   let mut seen: HashSet<Vec<u8>> = HashSet::new();
   let mut valid: u64 = 0;
   queue.push_back((0, vec![numbers[0]]));
-  while !queue.is_empty() {
-    let (index, current) = queue.pop_front().unwrap();
+  while let Some((index, current)) = queue.pop_front() {
     if seen.contains(&current) {
       continue;
     }
@@ -81,8 +75,7 @@ fn find_adapter_arrangements_oom(input: &str) -> u64 {
       continue;
     }
 
-    for i in index + 1..numbers.len() {
-      let next = numbers[i];
+    for (i, &next) in numbers.iter().enumerate().skip(index + 1) {
       let diff = current.last().unwrap() - next;
       if diff <= 3 {
         let mut path = current.clone();
@@ -94,28 +87,39 @@ fn find_adapter_arrangements_oom(input: &str) -> u64 {
   valid
 }
 
-fn part_one(input: &str) -> u16 {
-  let (one, three) = find_joltage_diffs(input);
-  return one as u16 * three as u16;
+fn part_one(numbers: &[u8]) -> u16 {
+  let (one, three) = find_joltage_diffs(numbers);
+  one as u16 * three as u16
 }
 
-fn part_two(input: &str) -> u64 {
-  let numbers = parse_input(input);
-  return find_adapter_arrangements(&numbers);
+fn part_two(numbers: &[u8]) -> u64 {
+  find_adapter_arrangements(numbers)
 }
 
-fn main() {
-  let input = read_input();
+impl Solution for Day10 {
+  type Input = Vec<u8>;
+  type PartOne = u16;
+  type PartTwo = u64;
 
-  let time = Instant::now();
-  let p1 = part_one(&input);
-  let p1_time = time.elapsed();
+  fn year(&self) -> u16 {
+    2020
+  }
 
-  let time = Instant::now();
-  let p2 = part_two(&input);
-  let p2_time = time.elapsed();
-  println!("part one {:?} {:?}", p1, p1_time);
-  println!("part two {:?} {:?}", p2, p2_time);
+  fn day(&self) -> u8 {
+    10
+  }
+
+  fn parse(&self, input: &str) -> Vec<u8> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<u8>) -> u16 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<u8>) -> u64 {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -168,20 +172,20 @@ mod test {
 
   #[test]
   fn test_find_joltage_diffs() {
-    assert_eq!(find_joltage_diffs(TEST_INPUT), (7, 5));
-    assert_eq!(find_joltage_diffs(TEST_INPUT_2), (22, 10));
+    assert_eq!(find_joltage_diffs(&parse_input(TEST_INPUT)), (7, 5));
+    assert_eq!(find_joltage_diffs(&parse_input(TEST_INPUT_2)), (22, 10));
   }
 
   #[test]
   fn test_find_adapter_arr() {
-    assert_eq!(find_adapter_arrangements(&vec![0, 1]), 1);
-    assert_eq!(find_adapter_arrangements(&vec![0, 3]), 1);
-    assert_eq!(find_adapter_arrangements(&vec![0, 3, 5]), 1);
-    assert_eq!(find_adapter_arrangements(&vec![0, 1, 2, 3]), 4);
-    assert_eq!(find_adapter_arrangements(&vec![0, 1, 2, 3, 4]), 7);
-    assert_eq!(find_adapter_arrangements(&vec![0, 1, 2, 3, 4, 5]), 13);
-    assert_eq!(find_adapter_arrangements(&vec![0, 3, 4, 5, 8]), 2);
-    assert_eq!(find_adapter_arrangements(&vec![0, 3, 4, 5, 6, 8]), 6);
+    assert_eq!(find_adapter_arrangements(&[0, 1]), 1);
+    assert_eq!(find_adapter_arrangements(&[0, 3]), 1);
+    assert_eq!(find_adapter_arrangements(&[0, 3, 5]), 1);
+    assert_eq!(find_adapter_arrangements(&[0, 1, 2, 3]), 4);
+    assert_eq!(find_adapter_arrangements(&[0, 1, 2, 3, 4]), 7);
+    assert_eq!(find_adapter_arrangements(&[0, 1, 2, 3, 4, 5]), 13);
+    assert_eq!(find_adapter_arrangements(&[0, 3, 4, 5, 8]), 2);
+    assert_eq!(find_adapter_arrangements(&[0, 3, 4, 5, 6, 8]), 6);
     assert_eq!(find_adapter_arrangements(&parse_input(TEST_INPUT)), 8);
     assert_eq!(find_adapter_arrangements(&parse_input(TEST_INPUT_2)), 19208);
     assert_eq!(find_adapter_arrangements_oom(TEST_INPUT_2), 19208);
@@ -189,15 +193,15 @@ mod test {
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(TEST_INPUT), 7 * 5);
-    assert_eq!(part_one(TEST_INPUT_2), 22 * 10);
-    assert_eq!(part_one(&read_input()), 2100);
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 7 * 5);
+    assert_eq!(part_one(&parse_input(TEST_INPUT_2)), 22 * 10);
+    assert_eq!(part_one(&parse_input(&Day10.input())), 2100);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(TEST_INPUT), 8);
-    assert_eq!(part_two(TEST_INPUT_2), 19208);
-    assert_eq!(part_two(&read_input()), 16198260678656);
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 8);
+    assert_eq!(part_two(&parse_input(TEST_INPUT_2)), 19208);
+    assert_eq!(part_two(&parse_input(&Day10.input())), 16198260678656);
   }
 }
@@ -0,0 +1,281 @@
+use crate::automaton::{Automaton, Neighbourhood, Rule, Settled};
+use crate::geom::Point2;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub struct Day11;
+
+fn parse_input(input: &str) -> Grid<char> {
+  Grid::parse(input, |c| c)
+}
+
+/// Seats are the domain, occupied seats the active cells. An empty seat fills up when no
+/// neighbour is occupied and an occupied one empties once `tolerance` neighbours are.
+fn seating(layout: &Grid<char>, neighbourhood: Neighbourhood, tolerance: usize) -> Automaton<2> {
+  let cells = |c: char| {
+    layout
+      .positions()
+      .filter(move |&pos| layout[pos] == c)
+      .map(Point2::embed)
+  };
+  let survival: Vec<usize> = (0..tolerance).collect();
+  Automaton::new(cells('#'), neighbourhood, Rule::new(&[0], &survival))
+    .with_domain(cells('L').chain(cells('#')))
+}
+
+fn occupied_when_stable(
+  layout: &Grid<char>,
+  neighbourhood: Neighbourhood,
+  tolerance: usize,
+) -> usize {
+  let mut seating = seating(layout, neighbourhood, tolerance);
+  assert!(
+    matches!(seating.run_until_stable(), Settled::Stable(_)),
+    "seating never settles"
+  );
+  seating.count()
+}
+
+fn part_one(layout: &Grid<char>) -> usize {
+  occupied_when_stable(layout, Neighbourhood::Moore, 4)
+}
+
+fn part_two(layout: &Grid<char>) -> usize {
+  occupied_when_stable(layout, Neighbourhood::LineOfSight, 5)
+}
+
+impl Solution for Day11 {
+  type Input = Grid<char>;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    11
+  }
+
+  fn parse(&self, input: &str) -> Grid<char> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Grid<char>) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Grid<char>) -> usize {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::grid::Pos;
+
+  fn visible_seats(layout: &Grid<char>, pos: Pos) -> usize {
+    seating(layout, Neighbourhood::LineOfSight, 5).active_neighbours(Point2::embed(pos))
+  }
+
+  fn step(layout: &Grid<char>, neighbourhood: Neighbourhood, tolerance: usize) -> Grid<char> {
+    let mut seating = seating(layout, neighbourhood, tolerance);
+    seating.step();
+    layout.map(|pos, &seat| match seat {
+      '.' => '.',
+      _ if seating.is_active(&Point2::embed(pos)) => '#',
+      _ => 'L',
+    })
+  }
+
+  fn mutate(layout: &Grid<char>) -> Grid<char> {
+    step(layout, Neighbourhood::Moore, 4)
+  }
+
+  fn mutate2(layout: &Grid<char>) -> Grid<char> {
+    step(layout, Neighbourhood::LineOfSight, 5)
+  }
+
+  const TEST_INPUT: &str = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+  #[test]
+  fn test_visible_seats() {
+    let state = ".......#.
+...#.....
+.#.......
+.........
+..#L....#
+....#....
+.........
+#........
+...#.....";
+    let point = (3, 4);
+    let state = parse_input(state);
+    assert_eq!(state[point], 'L');
+    assert_eq!(visible_seats(&state, point), 8);
+
+    let state = ".##.##.
+#.#.#.#
+##...##
+...L...
+##...##
+#.#.#.#
+.##.##.";
+    let point = (3, 3);
+    let state = parse_input(state);
+    assert_eq!(state[point], 'L');
+    assert_eq!(visible_seats(&state, point), 0);
+
+    let state = ".............
+.L.L.#.#.#.#.
+.............";
+    let point = (1, 1);
+    let state = parse_input(state);
+    assert_eq!(state[point], 'L');
+    assert_eq!(visible_seats(&state, point), 0);
+
+    let state = "#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##";
+    let state = parse_input(state);
+    let point = (0, 0);
+    assert_eq!(state[point], '#');
+    assert_eq!(visible_seats(&state, point), 3);
+
+    let state = "#.LL.LL.L#
+#LLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLL#
+#.LLLLLL.L
+#.LLLLL.L#";
+    let state = parse_input(state);
+    let point = (0, 2);
+    assert_eq!(state[point], 'L');
+    assert_eq!(visible_seats(&state, point), 1);
+    let point = (2, 0);
+    assert_eq!(state[point], 'L');
+    assert_eq!(visible_seats(&state, point), 1);
+  }
+
+  #[test]
+  fn test_mutate() {
+    let layout = parse_input("L.LL.LL.LL");
+    let expected = parse_input("#.##.##.##");
+    assert_eq!(mutate(&layout), expected)
+  }
+
+  #[test]
+  fn test_mutate_multi() {
+    let state1 = "#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##";
+    let state2 = "#.LL.L#.##
+#LLLLLL.L#
+L.L.L..L..
+#LLL.LL.L#
+#.LL.LL.LL
+#.LLLL#.##
+..L.L.....
+#LLLLLLLL#
+#.LLLLLL.L
+#.#LLLL.##";
+    let layout = parse_input(state1);
+    let expected = parse_input(state2);
+    assert_eq!(mutate(&layout), expected)
+  }
+
+  #[test]
+  fn test_mutate2() {
+    let state1 = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+    let state2 = "#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##";
+    let state3 = "#.LL.LL.L#
+#LLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLL#
+#.LLLLLL.L
+#.LLLLL.L#";
+    let state4 = "#.L#.##.L#
+#L#####.LL
+L.#.#..#..
+##L#.##.##
+#.##.#L.##
+#.#####.#L
+..#.#.....
+LLL####LL#
+#.L#####.L
+#.L####.L#";
+    assert_eq!(mutate2(&parse_input(state1)), parse_input(state2));
+    assert_eq!(mutate2(&parse_input(state2)), parse_input(state3));
+    assert_eq!(mutate2(&parse_input(state3)), parse_input(state4));
+  }
+
+  #[test]
+  fn test_parse_input() {
+    assert_eq!(
+      parse_input(TEST_INPUT).row(0),
+      &['L', '.', 'L', 'L', '.', 'L', 'L', '.', 'L', 'L']
+    )
+  }
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 37);
+    assert_eq!(part_one(&parse_input(&Day11.input())), 2441);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 26);
+    assert_eq!(part_two(&parse_input(&Day11.input())), 2190);
+  }
+}
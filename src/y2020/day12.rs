@@ -0,0 +1,134 @@
+use crate::geom::{quarters, Command, Heading, Point2};
+use crate::solution::Solution;
+
+pub struct Day12;
+
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+struct Ship {
+  facing: Heading,
+  pos: Point2,
+}
+
+impl Ship {
+  fn new() -> Ship {
+    Ship {
+      facing: Heading::East,
+      pos: Point2::origin(),
+    }
+  }
+
+  fn mv(&mut self, command: &Command) -> Ship {
+    match *command {
+      Command::Move(heading, steps) => self.pos += heading.delta() * steps,
+      Command::Turn(turn, degrees) => self.facing = self.facing.turn(turn, quarters(degrees)),
+      Command::Forward(steps) => self.pos += self.facing.delta() * steps,
+    }
+    *self
+  }
+
+  fn mv_towards_waypoint(&mut self, waypoint: &Point2, steps: i64) -> Ship {
+    self.pos += *waypoint * steps;
+    *self
+  }
+}
+
+fn parse_input(input: &str) -> Vec<Command> {
+  input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+fn part_one(instructions: &[Command]) -> i64 {
+  let mut ship = Ship::new();
+  for instruction in instructions {
+    ship.mv(instruction);
+  }
+  ship.pos.manhattan(&Point2::origin())
+}
+
+fn part_two(instructions: &[Command]) -> i64 {
+  let mut ship = Ship::new();
+  let mut waypoint = Point2::new(10, -1);
+  for instruction in instructions {
+    match *instruction {
+      Command::Move(heading, steps) => waypoint += heading.delta() * steps,
+      Command::Turn(turn, degrees) => waypoint = waypoint.rotate(turn, quarters(degrees)),
+      Command::Forward(steps) => {
+        ship.mv_towards_waypoint(&waypoint, steps);
+      }
+    }
+  }
+  ship.pos.manhattan(&Point2::origin())
+}
+
+impl Solution for Day12 {
+  type Input = Vec<Command>;
+  type PartOne = i64;
+  type PartTwo = i64;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    12
+  }
+
+  fn parse(&self, input: &str) -> Vec<Command> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<Command>) -> i64 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<Command>) -> i64 {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::geom::Turn;
+
+  const TEST_INPUT: &str = "F10
+N3
+F7
+R90
+F11
+";
+
+  #[test]
+  fn test_turning() {
+    let mut ship = Ship::new();
+    ship.mv(&"L270".parse().unwrap());
+    assert_eq!(ship.facing, Heading::South);
+    assert_eq!(ship.pos, Point2::origin());
+    ship.mv(&"R180".parse().unwrap());
+    assert_eq!(ship.mv(&"F3".parse().unwrap()).pos, Point2::new(0, -3));
+  }
+
+  #[test]
+  fn test_waypoint_rotation() {
+    let waypoint = Point2::new(10, -4);
+    assert_eq!(
+      waypoint.rotate(Turn::Right, quarters(90)),
+      Point2::new(4, 10)
+    );
+    assert_eq!(
+      waypoint.rotate(Turn::Left, quarters(180)),
+      Point2::new(-10, 4)
+    );
+  }
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 25);
+    assert_eq!(part_one(&parse_input(&Day12.input())), 1133);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 286);
+    assert_eq!(part_two(&parse_input(&Day12.input())), 61053);
+  }
+}
@@ -1,20 +1,16 @@
-use std::time::Instant;
-
 use itertools::Itertools;
 use num::Integer;
 
-use common::io;
+use crate::solution::Solution;
 
-fn read_input() -> String {
-  return io::read_input("2020-13");
-}
+pub struct Day13;
 
 fn parse_input(input: &str) -> (u64, Vec<u64>) {
   let lines: Vec<&str> = input.lines().collect();
   (
     lines[0].parse::<u64>().unwrap(),
     lines[1]
-      .split(",")
+      .split(',')
       .map(|s| {
         if s == "x" {
           0u64
@@ -26,8 +22,9 @@ fn parse_input(input: &str) -> (u64, Vec<u64>) {
   )
 }
 
-fn part_one(input: &str) -> usize {
-  let (timestamp, bus_ids) = parse_input(input);
+fn part_one(notes: &(u64, Vec<u64>)) -> usize {
+  let (timestamp, bus_ids) = notes;
+  let timestamp = *timestamp;
   let bus_ids = bus_ids
     .iter()
     .cloned()
@@ -59,14 +56,14 @@ fn lcm_multi(numbers: Vec<u64>) -> u64 {
   }
 }
 
-fn part_two(input: &str) -> u64 {
-  let (_timestamp, schedule) = parse_input(input);
+fn part_two(notes: &(u64, Vec<u64>)) -> u64 {
+  let (_timestamp, schedule) = notes;
   let bus_ids = schedule
     .iter()
     .cloned()
     .enumerate()
     .filter(|item| item.1 > 0u64)
-    .map(|item| (item.0 as u64, item.1 as u64))
+    .map(|item| (item.0 as u64, item.1))
     .collect::<Vec<(u64, u64)>>();
   let mut minute = 0u64;
   let mut step = bus_ids[0].1;
@@ -74,7 +71,7 @@ fn part_two(input: &str) -> u64 {
     minute += step;
     let departing_ids = bus_ids
       .iter()
-      .filter(|(wait, id)| (minute + wait) % id == 0)
+      .filter(|(wait, id)| (minute + wait).is_multiple_of(*id))
       .map(|(_wait, id)| id)
       .cloned()
       .collect::<Vec<u64>>();
@@ -87,18 +84,30 @@ fn part_two(input: &str) -> u64 {
   }
 }
 
-fn main() {
-  let input = read_input();
+impl Solution for Day13 {
+  type Input = (u64, Vec<u64>);
+  type PartOne = usize;
+  type PartTwo = u64;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    13
+  }
 
-  let time = Instant::now();
-  let p1 = part_one(&input);
-  let p1_time = time.elapsed();
+  fn parse(&self, input: &str) -> (u64, Vec<u64>) {
+    parse_input(input)
+  }
 
-  let time = Instant::now();
-  let p2 = part_two(&input);
-  let p2_time = time.elapsed();
-  println!("part one {:?} {:?}", p1, p1_time);
-  println!("part two {:?} {:?}", p2, p2_time);
+  fn part_one(&self, input: &(u64, Vec<u64>)) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &(u64, Vec<u64>)) -> u64 {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -127,18 +136,18 @@ mod test {
 
   #[test]
   fn test_part_one() {
-    assert_eq!(part_one(TEST_INPUT), 295);
-    assert_eq!(part_one(&read_input()), 410);
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 295);
+    assert_eq!(part_one(&parse_input(&Day13.input())), 410);
   }
 
   #[test]
   fn test_part_two() {
-    assert_eq!(part_two(TEST_INPUT), 1068781);
-    assert_eq!(part_two(TEST_INPUT_2), 3417);
-    assert_eq!(part_two(TEST_INPUT_3), 754018);
-    assert_eq!(part_two(TEST_INPUT_4), 779210);
-    assert_eq!(part_two(TEST_INPUT_5), 1261476);
-    assert_eq!(part_two(TEST_INPUT_6), 1202161486);
-    assert_eq!(part_two(&read_input()), 600691418730595);
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 1068781);
+    assert_eq!(part_two(&parse_input(TEST_INPUT_2)), 3417);
+    assert_eq!(part_two(&parse_input(TEST_INPUT_3)), 754018);
+    assert_eq!(part_two(&parse_input(TEST_INPUT_4)), 779210);
+    assert_eq!(part_two(&parse_input(TEST_INPUT_5)), 1261476);
+    assert_eq!(part_two(&parse_input(TEST_INPUT_6)), 1202161486);
+    assert_eq!(part_two(&parse_input(&Day13.input())), 600691418730595);
   }
 }
@@ -1,15 +1,11 @@
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
-use common::io;
+use crate::solution::Solution;
 
-fn read_input() -> String {
-  return io::read_input("2020-14");
-}
+pub struct Day14;
 
-#[allow(unused)]
-fn parse_input(input: &str) -> &str {
-  return input;
+fn parse_input(input: &str) -> String {
+  input.to_string()
 }
 
 fn dec_to_bin(n: usize) -> String {
@@ -54,22 +50,19 @@ fn possible_addresses(floating: &str) -> HashSet<String> {
 
   let mut s: Vec<char> = floating.chars().collect();
   for (i, c) in floating.chars().enumerate() {
-    match c {
-      'X' => {
-        s[i] = '1';
-        let floating1: String = s.iter().cloned().collect();
-        let p1 = possible_addresses(floating1.as_str());
-        for i in p1 {
-          perms.insert(i);
-        }
-        s[i] = '0';
-        let floating2: String = s.iter().cloned().collect();
-        let p1 = possible_addresses(floating2.as_str());
-        for i in p1 {
-          perms.insert(i);
-        }
+    if c == 'X' {
+      s[i] = '1';
+      let floating1: String = s.iter().cloned().collect();
+      let p1 = possible_addresses(floating1.as_str());
+      for i in p1 {
+        perms.insert(i);
+      }
+      s[i] = '0';
+      let floating2: String = s.iter().cloned().collect();
+      let p1 = possible_addresses(floating2.as_str());
+      for i in p1 {
+        perms.insert(i);
       }
-      _ => {}
     }
   }
   perms
@@ -85,12 +78,12 @@ fn part_one(input: &str) -> usize {
     } else {
       let (address, value): (usize, usize) = serde_scan::scan!("mem[{}] = {}" <- line).unwrap();
       let binary = dec_to_bin(value);
-      let masked = apply_mask(current_mask, &*binary);
-      let decimal = bin_to_dec(&*masked);
+      let masked = apply_mask(current_mask, &binary);
+      let decimal = bin_to_dec(&masked);
       mem.insert(address, decimal);
     }
   }
-  return mem.values().sum();
+  mem.values().sum()
 }
 
 fn part_two(input: &str) -> usize {
@@ -103,28 +96,40 @@ fn part_two(input: &str) -> usize {
     } else {
       let (address, value): (usize, usize) = serde_scan::scan!("mem[{}] = {}" <- line).unwrap();
       let binary = format!("{:036b}", address);
-      let masked = apply_mask_2(current_mask, &*binary);
-      for address in possible_addresses(&*masked) {
+      let masked = apply_mask_2(current_mask, &binary);
+      for address in possible_addresses(&masked) {
         let decimal = bin_to_dec(address.as_str());
         mem.insert(decimal, value);
       }
     }
   }
-  return mem.values().sum();
+  mem.values().sum()
 }
 
-fn main() {
-  let input = read_input();
+impl Solution for Day14 {
+  type Input = String;
+  type PartOne = usize;
+  type PartTwo = usize;
 
-  let time = Instant::now();
-  let p1 = part_one(&input);
-  let p1_time = time.elapsed();
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    14
+  }
 
-  let time = Instant::now();
-  let p2 = part_two(&input);
-  let p2_time = time.elapsed();
-  println!("part one {:?} {:?}", p1, p1_time);
-  println!("part two {:?} {:?}", p2, p2_time);
+  fn parse(&self, input: &str) -> String {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &String) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &String) -> usize {
+    part_two(input)
+  }
 }
 
 #[cfg(test)]
@@ -207,12 +212,12 @@ mem[26] = 1";
   #[test]
   fn test_part_one() {
     assert_eq!(part_one(TEST_INPUT), 165);
-    assert_eq!(part_one(&read_input()), 9615006043476);
+    assert_eq!(part_one(&Day14.input()), 9615006043476);
   }
 
   #[test]
   fn test_part_two() {
     assert_eq!(part_two(TEST_INPUT_2), 208);
-    assert_eq!(part_two(&read_input()), 4275496544925);
+    assert_eq!(part_two(&Day14.input()), 4275496544925);
   }
 }
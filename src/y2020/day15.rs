@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use crate::solution::Solution;
+
+pub struct Day15;
+
+fn parse_input(input: &str) -> Vec<usize> {
+  input
+    .lines()
+    .next()
+    .unwrap()
+    .split(',')
+    .map(|c| c.parse::<usize>().unwrap())
+    .collect()
+}
+
+#[allow(unused)]
+fn get_nth_number_vec(initial: &[usize], n: usize) -> usize {
+  let mut last_spoken = HashMap::<usize, Vec<usize>>::new();
+  let mut last = 0;
+  for (i, n) in initial.iter().enumerate() {
+    last_spoken.entry(*n).or_default().push(i + 1);
+    last = *n;
+  }
+  let mut i = initial.len() + 1;
+  while i <= n {
+    let indices = last_spoken.get(&last).unwrap();
+    let count = indices.len();
+    if count == 1 {
+      last = 0;
+      last_spoken.entry(last).or_default().push(i);
+    } else {
+      let (i1, i2) = (indices.last().unwrap(), indices[count - 2]);
+      last = i1 - i2;
+      last_spoken.entry(last).or_default().push(i);
+    }
+    i += 1;
+  }
+  last
+}
+
+fn get_nth_number_2(initial: &[usize], n: usize) -> usize {
+  let mut last_spoken = HashMap::<usize, (Option<usize>, Option<usize>)>::new();
+  let mut last = 0;
+  for (i, n) in initial.iter().enumerate() {
+    last_spoken.entry(*n).or_insert((Option::from(i + 1), None));
+    last = *n;
+  }
+  let mut i = initial.len() + 1;
+  while i <= n {
+    let (i1, i2) = *last_spoken.get(&last).unwrap();
+    if let Some(i2) = i2 {
+      last = i2 - i1.unwrap();
+      let (i1, i2) = *last_spoken.entry(last).or_insert((Option::from(i), None));
+      last_spoken.insert(last, (i2.or(i1), Option::from(i)));
+    } else {
+      last = 0;
+      let (i1, _i2) = *last_spoken.get(&last).unwrap();
+      last_spoken.insert(last, (i1, Option::from(i)));
+    }
+    i += 1;
+  }
+  last
+}
+
+fn part_one(initial: &[usize]) -> usize {
+  // get_nth_number(initial, 2020)
+  get_nth_number_2(initial, 2020)
+}
+
+fn part_two(initial: &[usize]) -> usize {
+  // get_nth_number(initial, 30000000)
+  get_nth_number_2(initial, 30000000)
+}
+
+impl Solution for Day15 {
+  type Input = Vec<usize>;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    15
+  }
+
+  fn parse(&self, input: &str) -> Vec<usize> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<usize>) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<usize>) -> usize {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input("0,3,6")), 436);
+    assert_eq!(part_one(&parse_input(&Day15.input())), 276);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input("0,3,6")), 175594);
+    assert_eq!(part_two(&parse_input(&Day15.input())), 31916);
+  }
+}
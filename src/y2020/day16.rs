@@ -1,15 +1,14 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
 use itertools::Itertools;
 
-use common::io;
+use crate::solution::Solution;
 
-fn read_input() -> String {
-  return io::read_input("2020-16");
-}
+pub struct Day16;
+
+type Notes = (HashMap<String, Vec<usize>>, Vec<Vec<usize>>, Vec<usize>);
 
-fn parse_input(input: &str) -> (HashMap<String, Vec<usize>>, Vec<Vec<usize>>, Vec<usize>) {
+fn parse_input(input: &str) -> Notes {
   let sections = input.split("\n\n").collect::<Vec<&str>>();
 
   let field_rules = sections[0]
@@ -26,7 +25,7 @@ fn parse_input(input: &str) -> (HashMap<String, Vec<usize>>, Vec<Vec<usize>>, Ve
     .nth(1) // skip 'your ticket:'
     .map(|line| {
       line
-        .split(",")
+        .split(',')
         .map(|c| c.parse::<usize>().unwrap())
         .collect::<Vec<usize>>()
     })
@@ -37,26 +36,26 @@ fn parse_input(input: &str) -> (HashMap<String, Vec<usize>>, Vec<Vec<usize>>, Ve
     .dropping(1) // drop nearby tickets:
     .map(|line| {
       line
-        .split(",")
+        .split(',')
         .map(|c| c.parse::<usize>().unwrap())
         .collect()
     })
     .collect();
-  return (field_rules, nearby_tickets, my_ticket);
+  (field_rules, nearby_tickets, my_ticket)
 }
 
 fn invalid_fields_for_ticket(
-  ticket: &Vec<usize>,
+  ticket: &[usize],
   field_rules: &HashMap<String, Vec<usize>>,
 ) -> Vec<usize> {
   ticket
-    .into_iter()
+    .iter()
     .filter(|field| !valid_field(field, field_rules))
     .cloned()
     .collect()
 }
 
-fn valid_ticket(ticket: &Vec<usize>, field_rules: &HashMap<String, Vec<usize>>) -> bool {
+fn valid_ticket(ticket: &[usize], field_rules: &HashMap<String, Vec<usize>>) -> bool {
   ticket.iter().all(|field| valid_field(field, field_rules))
 }
 
@@ -65,32 +64,32 @@ fn valid_field(field: &usize, field_rules: &HashMap<String, Vec<usize>>) -> bool
 }
 
 fn rule_applies_to_all_tickets(
-  tickets: &Vec<Vec<usize>>,
+  tickets: &[Vec<usize>],
   field_index: &usize,
-  rule_range: &Vec<usize>,
+  rule_range: &[usize],
 ) -> bool {
   tickets
-    .into_iter()
+    .iter()
     .map(|ticket| ticket[*field_index])
     .all(|field| rule_range.contains(&field))
 }
 
-fn part_one(field_rules: &HashMap<String, Vec<usize>>, nearby_tickets: &Vec<Vec<usize>>) -> usize {
-  let all_nearby_ticket_fields = nearby_tickets.into_iter().flatten().cloned().collect();
-  return invalid_fields_for_ticket(&all_nearby_ticket_fields, field_rules)
+fn part_one(field_rules: &HashMap<String, Vec<usize>>, nearby_tickets: &[Vec<usize>]) -> usize {
+  let all_nearby_ticket_fields: Vec<usize> = nearby_tickets.iter().flatten().cloned().collect();
+  invalid_fields_for_ticket(&all_nearby_ticket_fields, field_rules)
     .into_iter()
-    .sum();
+    .sum()
 }
 
 fn part_two(
   field_rules: &HashMap<String, Vec<usize>>,
-  nearby_tickets: &Vec<Vec<usize>>,
-  my_ticket: &Vec<usize>,
+  nearby_tickets: &[Vec<usize>],
+  my_ticket: &[usize],
   find_fields: Vec<&str>,
 ) -> usize {
-  let valid_tickets = nearby_tickets
-    .into_iter()
-    .filter(|ticket| valid_ticket(ticket, &field_rules))
+  let valid_tickets: Vec<Vec<usize>> = nearby_tickets
+    .iter()
+    .filter(|ticket| valid_ticket(ticket, field_rules))
     .cloned()
     .collect();
 
@@ -99,7 +98,7 @@ fn part_two(
       (
         index,
         field_rules
-          .into_iter()
+          .iter()
           .filter(|(_, valid_range)| {
             rule_applies_to_all_tickets(&valid_tickets, &index, valid_range)
           })
@@ -107,7 +106,6 @@ fn part_two(
           .collect::<Vec<&String>>(),
       )
     })
-    .into_iter()
     .sorted_by(|a, b| Ord::cmp(&a.1.len(), &b.1.len()))
     .collect::<Vec<(usize, Vec<&String>)>>();
 
@@ -126,19 +124,32 @@ fn part_two(
     .product()
 }
 
-fn main() {
-  let input = read_input();
-  let (field_rules, nearby_tickets, my_ticket) = parse_input(&input);
+impl Solution for Day16 {
+  type Input = Notes;
+  type PartOne = usize;
+  type PartTwo = usize;
 
-  let time = Instant::now();
-  let p1 = part_one(&field_rules, &nearby_tickets);
-  let p1_time = time.elapsed();
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    16
+  }
+
+  fn parse(&self, input: &str) -> Notes {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Notes) -> usize {
+    let (field_rules, nearby_tickets, _) = input;
+    part_one(field_rules, nearby_tickets)
+  }
 
-  let time = Instant::now();
-  let p2 = part_two(&field_rules, &nearby_tickets, &my_ticket, vec!["departure"]);
-  let p2_time = time.elapsed();
-  println!("part one {:?} {:?}", p1, p1_time);
-  println!("part two {:?} {:?}", p2, p2_time);
+  fn part_two(&self, input: &Notes) -> usize {
+    let (field_rules, nearby_tickets, my_ticket) = input;
+    part_two(field_rules, nearby_tickets, my_ticket, vec!["departure"])
+  }
 }
 
 #[cfg(test)]
@@ -174,7 +185,7 @@ nearby tickets:
   fn test_part_one() {
     let (field_rules, nearby_tickets, _) = parse_input(TEST_INPUT);
     assert_eq!(part_one(&field_rules, &nearby_tickets), 71);
-    let (field_rules, nearby_tickets, _) = parse_input(&read_input());
+    let (field_rules, nearby_tickets, _) = parse_input(&Day16.input());
     assert_eq!(part_one(&field_rules, &nearby_tickets), 22073);
   }
 
@@ -190,7 +201,7 @@ nearby tickets:
       ),
       11 * 12 * 13
     );
-    let (field_rules, nearby_tickets, my_ticket) = parse_input(&read_input());
+    let (field_rules, nearby_tickets, my_ticket) = parse_input(&Day16.input());
     assert_eq!(
       part_two(&field_rules, &nearby_tickets, &my_ticket, vec!["departure"]),
       1346570764607
@@ -0,0 +1,92 @@
+use crate::automaton::{Automaton, Neighbourhood, Rule};
+use crate::geom::Point;
+use crate::grid::{Grid, Pos};
+use crate::solution::Solution;
+
+pub struct Day17;
+
+/// The active cubes of the initial slice.
+fn parse_input(input: &str) -> Vec<Pos> {
+  let slice = Grid::parse(input, |c| c == '#');
+  slice.positions().filter(|&pos| slice[pos]).collect()
+}
+
+/// Active cubes after `cycles` cycles in `N` dimensions, starting from the slice at the
+/// origin of every axis past `y`.
+fn boot<const N: usize>(slice: &[Pos], cycles: usize) -> usize {
+  let cubes = slice.iter().map(|&pos| Point::<N>::embed(pos));
+  Automaton::new(cubes, Neighbourhood::Moore, Rule::life())
+    .run(cycles)
+    .count()
+}
+
+fn part_one(slice: &[Pos]) -> usize {
+  boot::<3>(slice, 6)
+}
+
+fn part_two(slice: &[Pos]) -> usize {
+  boot::<4>(slice, 6)
+}
+
+impl Solution for Day17 {
+  type Input = Vec<Pos>;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    17
+  }
+
+  fn parse(&self, input: &str) -> Vec<Pos> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<Pos>) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<Pos>) -> usize {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TEST_INPUT: &str = ".#.
+..#
+###";
+
+  #[test]
+  fn test_parse_input() {
+    assert_eq!(
+      parse_input(TEST_INPUT),
+      vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+    );
+  }
+
+  #[test]
+  fn test_boot() {
+    let slice = parse_input(TEST_INPUT);
+    assert_eq!(boot::<2>(&slice, 1), 5);
+    assert_eq!(boot::<3>(&slice, 1), 11);
+    assert_eq!(boot::<3>(&slice, 3), 38);
+  }
+
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 112);
+    assert_eq!(part_one(&parse_input(&Day17.input())), 346);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 848);
+    assert_eq!(part_two(&parse_input(&Day17.input())), 1632);
+  }
+}
@@ -0,0 +1,407 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::slice::Iter;
+
+use crate::solution::Solution;
+
+pub struct Day18;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Op {
+  Add,
+  Mul,
+}
+
+impl Op {
+  fn apply(self, a: u64, b: u64) -> u64 {
+    match self {
+      Op::Add => a + b,
+      Op::Mul => a * b,
+    }
+  }
+}
+
+impl Display for Op {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Op::Add => write!(f, "+"),
+      Op::Mul => write!(f, "*"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Token {
+  Num(u64),
+  Op(Op),
+  Open,
+  Close,
+}
+
+/// How tightly each operator binds, higher first. Operators of equal precedence group left
+/// to right.
+pub type Precedence = [(Op, u8); 2];
+
+/// The homework rules: no precedence at all, just left to right.
+pub const LEFT_TO_RIGHT: Precedence = [(Op::Add, 1), (Op::Mul, 1)];
+/// The advanced rules: addition before multiplication.
+pub const ADDITION_FIRST: Precedence = [(Op::Add, 2), (Op::Mul, 1)];
+
+fn binding_power(precedence: &Precedence, op: Op) -> u8 {
+  precedence
+    .iter()
+    .find(|(o, _)| *o == op)
+    .map(|(_, power)| *power)
+    .unwrap_or_else(|| panic!("no precedence for {:?}", op))
+}
+
+/// Splits a line into tokens. Panics on anything but digits, `+`, `*`, parentheses and
+/// whitespace.
+fn tokenize(line: &str) -> Vec<Token> {
+  let mut tokens = vec![];
+  let mut chars = line.chars().peekable();
+  while let Some(c) = chars.next() {
+    let token = match c {
+      '+' => Token::Op(Op::Add),
+      '*' => Token::Op(Op::Mul),
+      '(' => Token::Open,
+      ')' => Token::Close,
+      c if c.is_whitespace() => continue,
+      c if c.is_ascii_digit() => {
+        let mut n = c.to_digit(10).unwrap() as u64;
+        while let Some(d) = chars.peek().and_then(|d| d.to_digit(10)) {
+          n = n * 10 + d as u64;
+          chars.next();
+        }
+        Token::Num(n)
+      }
+      _ => panic!("unexpected {:?} in {:?}", c, line),
+    };
+    tokens.push(token);
+  }
+  tokens
+}
+
+fn parse_input(input: &str) -> Vec<Vec<Token>> {
+  input
+    .lines()
+    .filter(|l| !l.trim().is_empty())
+    .map(tokenize)
+    .collect()
+}
+
+/// Every operation in evaluation order, each with the expression left after it.
+pub type Trace = Vec<(Step, Expr)>;
+
+/// A homework line with the grouping decided, so parentheses are implied by the tree.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Expr {
+  Num(u64),
+  BinOp(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+  pub fn value(&self) -> u64 {
+    match self {
+      Expr::Num(n) => *n,
+      Expr::BinOp(op, lhs, rhs) => op.apply(lhs.value(), rhs.value()),
+    }
+  }
+
+  /// Carries out the leftmost operation whose operands are both numbers, or `None` once the
+  /// expression is a single number.
+  pub fn reduce(&self) -> Option<(Step, Expr)> {
+    match self {
+      Expr::Num(_) => None,
+      Expr::BinOp(op, lhs, rhs) => match (lhs.as_ref(), rhs.as_ref()) {
+        (Expr::Num(a), Expr::Num(b)) => {
+          let step = Step {
+            lhs: *a,
+            op: *op,
+            rhs: *b,
+          };
+          Some((step, Expr::Num(step.value())))
+        }
+        _ => match lhs.reduce() {
+          Some((step, lhs)) => Some((step, Expr::BinOp(*op, Box::new(lhs), rhs.clone()))),
+          None => {
+            let (step, rhs) = rhs.reduce()?;
+            Some((step, Expr::BinOp(*op, lhs.clone(), Box::new(rhs))))
+          }
+        },
+      },
+    }
+  }
+
+  /// Reduces one operation at a time down to a single number.
+  pub fn trace(&self) -> Trace {
+    let mut trace: Trace = vec![];
+    let mut expr = self;
+    while let Some(reduced) = expr.reduce() {
+      trace.push(reduced);
+      expr = &trace.last().unwrap().1;
+    }
+    trace
+  }
+}
+
+/// Prints every implied parenthesis except the outermost pair, as in `(2 * 3) + (4 * 5)`.
+impl Display for Expr {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Expr::Num(n) => write!(f, "{}", n),
+      Expr::BinOp(op, lhs, rhs) => {
+        for (i, operand) in [lhs, rhs].iter().enumerate() {
+          if i > 0 {
+            write!(f, " {} ", op)?;
+          }
+          match operand.as_ref() {
+            Expr::Num(n) => write!(f, "{}", n)?,
+            nested => write!(f, "({})", nested)?,
+          }
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A single operation on two numbers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Step {
+  pub lhs: u64,
+  pub op: Op,
+  pub rhs: u64,
+}
+
+impl Step {
+  pub fn value(&self) -> u64 {
+    self.op.apply(self.lhs, self.rhs)
+  }
+}
+
+impl Display for Step {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} {} {} = {}",
+      self.lhs,
+      self.op,
+      self.rhs,
+      self.value()
+    )
+  }
+}
+
+/// Pratt parser, only taking operators that bind tighter than `min_power` into the right hand
+/// side.
+fn parse_tokens(
+  tokens: &mut Peekable<Iter<Token>>,
+  precedence: &Precedence,
+  min_power: u8,
+) -> Expr {
+  let mut lhs = match tokens.next() {
+    Some(Token::Num(n)) => Expr::Num(*n),
+    Some(Token::Open) => {
+      let expr = parse_tokens(tokens, precedence, 0);
+      assert_eq!(tokens.next(), Some(&Token::Close), "unbalanced parentheses");
+      expr
+    }
+    token => panic!("expected a number or '(', got {:?}", token),
+  };
+  while let Some(&&Token::Op(op)) = tokens.peek() {
+    let power = binding_power(precedence, op);
+    if power <= min_power {
+      break;
+    }
+    tokens.next();
+    let rhs = parse_tokens(tokens, precedence, power);
+    lhs = Expr::BinOp(op, Box::new(lhs), Box::new(rhs));
+  }
+  lhs
+}
+
+/// Builds the tree for one line. Panics on unbalanced parentheses or misplaced operators.
+pub fn parse_expr(tokens: &[Token], precedence: &Precedence) -> Expr {
+  let mut tokens = tokens.iter().peekable();
+  let expr = parse_tokens(&mut tokens, precedence, 0);
+  assert_eq!(tokens.next(), None, "trailing tokens");
+  expr
+}
+
+fn eval(tokens: &[Token], precedence: &Precedence) -> u64 {
+  parse_expr(tokens, precedence).value()
+}
+
+/// The first step at which evaluating `tokens` under `expected` and `actual` carries out a
+/// different operation, with both traces, or `None` if the two agree throughout.
+pub fn divergence(
+  tokens: &[Token],
+  expected: &Precedence,
+  actual: &Precedence,
+) -> Option<(usize, Trace, Trace)> {
+  let expected = parse_expr(tokens, expected).trace();
+  let actual = parse_expr(tokens, actual).trace();
+  let split = expected
+    .iter()
+    .zip(actual.iter())
+    .position(|((a, _), (b, _))| a != b)?;
+  Some((split, expected, actual))
+}
+
+fn sum(homework: &[Vec<Token>], precedence: &Precedence) -> u64 {
+  homework.iter().map(|line| eval(line, precedence)).sum()
+}
+
+fn part_one(homework: &[Vec<Token>]) -> u64 {
+  sum(homework, &LEFT_TO_RIGHT)
+}
+
+fn part_two(homework: &[Vec<Token>]) -> u64 {
+  sum(homework, &ADDITION_FIRST)
+}
+
+impl Solution for Day18 {
+  type Input = Vec<Vec<Token>>;
+  type PartOne = u64;
+  type PartTwo = u64;
+
+  fn year(&self) -> u16 {
+    2020
+  }
+
+  fn day(&self) -> u8 {
+    18
+  }
+
+  fn parse(&self, input: &str) -> Vec<Vec<Token>> {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &Vec<Vec<Token>>) -> u64 {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &Vec<Vec<Token>>) -> u64 {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TEST_INPUT: &str = "2 * 3 + (4 * 5)
+5 + (8 * 3 + 9 + 3 * 4 * 3)
+5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))
+((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2";
+
+  fn eval_expr(expr: &str) -> u64 {
+    eval(&tokenize(expr), &LEFT_TO_RIGHT)
+  }
+
+  #[test]
+  fn test_tokenize() {
+    assert_eq!(
+      tokenize("(12+3) * 4"),
+      vec![
+        Token::Open,
+        Token::Num(12),
+        Token::Op(Op::Add),
+        Token::Num(3),
+        Token::Close,
+        Token::Op(Op::Mul),
+        Token::Num(4)
+      ]
+    );
+  }
+
+  #[test]
+  fn test_eval_expr() {
+    assert_eq!(eval_expr("1"), 1);
+    assert_eq!(eval_expr("1 + 2"), 3);
+    assert_eq!(eval_expr("1 * 2"), 2);
+    assert_eq!(eval_expr("1 * 2 * 3"), 6);
+    assert_eq!(eval_expr("1 + (2 * 3)"), 7);
+    assert_eq!(eval_expr("(1 * 2) + (2 * 3)"), 8);
+    assert_eq!(eval_expr("(1 * 2) * (2 * 3)"), 12);
+    assert_eq!(eval_expr("(1 * (2+3)) * (2 * 3)"), 30);
+    assert_eq!(eval_expr("10 + 25 * 2"), 70);
+    assert_eq!(eval(&tokenize("10 + 25 * 2"), &ADDITION_FIRST), 70);
+    assert_eq!(eval(&tokenize("2 * 10 + 25"), &ADDITION_FIRST), 70);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_eval_unbalanced() {
+    eval_expr("(1 + 2");
+  }
+
+  #[test]
+  fn test_pretty_print() {
+    let tokens = tokenize("1 + 2 * 3 + 4 * 5 + 6");
+    assert_eq!(
+      parse_expr(&tokens, &LEFT_TO_RIGHT).to_string(),
+      "((((1 + 2) * 3) + 4) * 5) + 6"
+    );
+    assert_eq!(
+      parse_expr(&tokens, &ADDITION_FIRST).to_string(),
+      "((1 + 2) * (3 + 4)) * (5 + 6)"
+    );
+    let tokens = tokenize("2 * (3 + 4)");
+    assert_eq!(
+      parse_expr(&tokens, &LEFT_TO_RIGHT).to_string(),
+      "2 * (3 + 4)"
+    );
+  }
+
+  #[test]
+  fn test_trace() {
+    let expr = parse_expr(&tokenize("1 + (2 * 3) + 4"), &LEFT_TO_RIGHT);
+    let steps: Vec<String> = expr
+      .trace()
+      .iter()
+      .map(|(step, rest)| format!("{} -> {}", step, rest))
+      .collect();
+    assert_eq!(
+      steps,
+      vec![
+        "2 * 3 = 6 -> (1 + 6) + 4",
+        "1 + 6 = 7 -> 7 + 4",
+        "7 + 4 = 11 -> 11"
+      ]
+    );
+    assert!(Expr::Num(5).trace().is_empty());
+  }
+
+  #[test]
+  fn test_divergence() {
+    let tokens = tokenize("1 + 2 * 3 + 4");
+    let (split, expected, actual) = divergence(&tokens, &ADDITION_FIRST, &LEFT_TO_RIGHT).unwrap();
+    assert_eq!(split, 1);
+    assert_eq!(expected[1].0.to_string(), "3 + 4 = 7");
+    assert_eq!(actual[1].0.to_string(), "3 * 3 = 9");
+    assert_eq!(
+      divergence(&tokenize("1 + 2 + 3"), &ADDITION_FIRST, &LEFT_TO_RIGHT),
+      None
+    );
+  }
+
+  #[test]
+  fn test_part_one() {
+    let homework = parse_input(TEST_INPUT);
+    let answers: Vec<u64> = homework.iter().map(|l| eval(l, &LEFT_TO_RIGHT)).collect();
+    assert_eq!(answers, vec![26, 437, 12240, 13632]);
+    assert_eq!(part_one(&parse_input(&Day18.input())), 3647606140187);
+  }
+
+  #[test]
+  fn test_part_two() {
+    let homework = parse_input(TEST_INPUT);
+    let answers: Vec<u64> = homework.iter().map(|l| eval(l, &ADDITION_FIRST)).collect();
+    assert_eq!(answers, vec![46, 1445, 669060, 23340]);
+    assert_eq!(part_two(&homework), 46 + 1445 + 669060 + 23340);
+    assert_eq!(part_two(&parse_input(&Day18.input())), 323802071857594);
+  }
+}
@@ -0,0 +1,41 @@
+use crate::solution::Registry;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+
+pub fn register(registry: &mut Registry) {
+  registry.register(day01::Day01);
+  registry.register(day02::Day02);
+  registry.register(day03::Day03);
+  registry.register(day04::Day04);
+  registry.register(day05::Day05);
+  registry.register(day06::Day06);
+  registry.register(day07::Day07);
+  registry.register(day08::Day08);
+  registry.register(day09::Day09);
+  registry.register(day10::Day10);
+  registry.register(day11::Day11);
+  registry.register(day12::Day12);
+  registry.register(day13::Day13);
+  registry.register(day14::Day14);
+  registry.register(day15::Day15);
+  registry.register(day16::Day16);
+  registry.register(day17::Day17);
+  registry.register(day18::Day18);
+}
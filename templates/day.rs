@@ -0,0 +1,57 @@
+use crate::solution::Solution;
+
+pub struct Day{{day}};
+
+fn parse_input(input: &str) -> String {
+  input.to_string()
+}
+
+fn part_one(_input: &str) -> usize {
+  0
+}
+
+fn part_two(_input: &str) -> usize {
+  0
+}
+
+impl Solution for Day{{day}} {
+  type Input = String;
+  type PartOne = usize;
+  type PartTwo = usize;
+
+  fn year(&self) -> u16 {
+    {{year}}
+  }
+
+  fn day(&self) -> u8 {
+    {{day_number}}
+  }
+
+  fn parse(&self, input: &str) -> String {
+    parse_input(input)
+  }
+
+  fn part_one(&self, input: &String) -> usize {
+    part_one(input)
+  }
+
+  fn part_two(&self, input: &String) -> usize {
+    part_two(input)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+{{test_inputs}}
+  #[test]
+  fn test_part_one() {
+    assert_eq!(part_one(&parse_input(TEST_INPUT)), 0);
+  }
+
+  #[test]
+  fn test_part_two() {
+    assert_eq!(part_two(&parse_input(TEST_INPUT)), 0);
+  }
+}